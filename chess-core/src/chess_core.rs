@@ -0,0 +1,30 @@
+//! Chess rules shared by the contract and the off-chain tools.
+//!
+//! Everything here is a pure function over a [`Position`], so it runs the same in the
+//! contract, in the interactor and in plain unit tests.
+
+#![no_std]
+
+pub mod chess_core_attacks;
+pub mod chess_core_bitboards;
+pub mod chess_core_board;
+pub mod chess_core_bot;
+pub mod chess_core_chess960;
+pub mod chess_core_fen;
+pub mod chess_core_movegen;
+pub mod chess_core_moves;
+pub mod chess_core_notation;
+pub mod chess_core_pgn;
+pub mod chess_core_variants;
+
+pub use chess_core_attacks::*;
+pub use chess_core_bitboards::*;
+pub use chess_core_board::*;
+pub use chess_core_bot::*;
+pub use chess_core_chess960::*;
+pub use chess_core_fen::*;
+pub use chess_core_movegen::*;
+pub use chess_core_moves::*;
+pub use chess_core_notation::*;
+pub use chess_core_pgn::*;
+pub use chess_core_variants::*;
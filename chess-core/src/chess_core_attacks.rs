@@ -0,0 +1,34 @@
+use crate::{chess_core_bitboards::*, chess_core_board::*};
+
+pub const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+pub const KING_OFFSETS: [(i8, i8); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// Whether any piece of color `by` attacks `square`.
+pub fn is_square_attacked(board: &Board, square: Square, by: u8) -> bool {
+    Bitboards::from_board(board).is_attacked(square, by)
+}
+
+/// Whether the king of `color` is attacked. A side without a king counts as in check,
+/// its king was taken after an illegal move that nobody called out.
+pub fn is_in_check(board: &Board, color: u8) -> bool {
+    Bitboards::from_board(board).is_in_check(color)
+}
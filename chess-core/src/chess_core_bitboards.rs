@@ -0,0 +1,164 @@
+use crate::{chess_core_attacks::*, chess_core_board::*};
+
+/// One bit per square, bit `0` is a8 and bit `63` is h1.
+pub type Bitboard = u64;
+
+pub const fn bit(square: Square) -> Bitboard {
+    1 << square
+}
+
+const fn step_table(offsets: &[(i8, i8)]) -> [Bitboard; 64] {
+    let mut table = [0; 64];
+    let mut square = 0;
+    while square < 64 {
+        let mut i = 0;
+        while i < offsets.len() {
+            let (dx, dy) = offsets[i];
+            if let Some(to) = square_at(x_of(square) as i8 + dx, y_of(square) as i8 + dy) {
+                table[square as usize] |= bit(to);
+            }
+            i += 1;
+        }
+        square += 1;
+    }
+    table
+}
+
+const fn ray_table(dx: i8, dy: i8) -> [Bitboard; 64] {
+    let mut table = [0; 64];
+    let mut square = 0;
+    while square < 64 {
+        let mut i = 1;
+        while let Some(to) = square_at(x_of(square) as i8 + dx * i, y_of(square) as i8 + dy * i) {
+            table[square as usize] |= bit(to);
+            i += 1;
+        }
+        square += 1;
+    }
+    table
+}
+
+pub const KNIGHT_ATTACKS: [Bitboard; 64] = step_table(&KNIGHT_OFFSETS);
+pub const KING_ATTACKS: [Bitboard; 64] = step_table(&KING_OFFSETS);
+
+/// The squares a pawn of each color attacks from a square.
+pub const PAWN_ATTACKS: [[Bitboard; 64]; 2] = [
+    step_table(&[(-1, pawn_direction(WHITE)), (1, pawn_direction(WHITE))]),
+    step_table(&[(-1, pawn_direction(BLACK)), (1, pawn_direction(BLACK))]),
+];
+
+// rays going towards higher squares come first, their nearest blocker is the lowest bit
+const STRAIGHT_RAYS: [[Bitboard; 64]; 4] = [
+    ray_table(1, 0),
+    ray_table(0, 1),
+    ray_table(-1, 0),
+    ray_table(0, -1),
+];
+const DIAGONAL_RAYS: [[Bitboard; 64]; 4] = [
+    ray_table(1, 1),
+    ray_table(-1, 1),
+    ray_table(-1, -1),
+    ray_table(1, -1),
+];
+
+fn slider_attacks(rays: &[[Bitboard; 64]; 4], square: Square, occupied: Bitboard) -> Bitboard {
+    let mut attacks = 0;
+    for (i, ray) in rays.iter().enumerate() {
+        let mut squares = ray[square as usize];
+        let blockers = squares & occupied;
+        if blockers != 0 {
+            let blocker = if i < 2 {
+                blockers.trailing_zeros()
+            } else {
+                63 - blockers.leading_zeros()
+            };
+            squares ^= ray[blocker as usize];
+        }
+        attacks |= squares;
+    }
+    attacks
+}
+
+pub fn rook_attacks(square: Square, occupied: Bitboard) -> Bitboard {
+    slider_attacks(&STRAIGHT_RAYS, square, occupied)
+}
+
+pub fn bishop_attacks(square: Square, occupied: Bitboard) -> Bitboard {
+    slider_attacks(&DIAGONAL_RAYS, square, occupied)
+}
+
+/// A bitboard per piece code, the same codes as the `Board`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Bitboards {
+    pub pieces: [Bitboard; 13],
+    pub occupied: Bitboard,
+}
+
+impl Bitboards {
+    pub fn from_board(board: &Board) -> Self {
+        let mut bitboards = Bitboards {
+            pieces: [0; 13],
+            occupied: 0,
+        };
+        for (square, &piece) in board.iter().enumerate() {
+            if piece != EMPTY {
+                bitboards.pieces[piece as usize] |= bit(square as Square);
+                bitboards.occupied |= bit(square as Square);
+            }
+        }
+        bitboards
+    }
+
+    /// Reads the nibbles of a packed board without unpacking it first.
+    pub fn from_packed(packed: &PackedBoard) -> Self {
+        let mut bitboards = Bitboards {
+            pieces: [0; 13],
+            occupied: 0,
+        };
+        for (i, &byte) in packed.iter().enumerate() {
+            // most bytes hold two empty squares
+            if byte == EMPTY | EMPTY << 4 {
+                continue;
+            }
+            bitboards.pieces[(byte & 0x0f) as usize] |= bit(2 * i as Square);
+            bitboards.pieces[(byte >> 4) as usize] |= bit(2 * i as Square + 1);
+        }
+        bitboards.pieces[EMPTY as usize] = 0;
+        bitboards.occupied = bitboards.pieces.iter().fold(0, |all, pieces| all | pieces);
+        bitboards
+    }
+
+    pub fn piece(&self, color: u8, kind: u8) -> Bitboard {
+        self.pieces[make_piece(color, kind) as usize]
+    }
+
+    pub fn color(&self, color: u8) -> Bitboard {
+        let first = make_piece(color, KING) as usize;
+        self.pieces[first..first + 6]
+            .iter()
+            .fold(0, |all, pieces| all | pieces)
+    }
+
+    /// The pieces of color `by` that attack `square`.
+    pub fn attackers(&self, square: Square, by: u8) -> Bitboard {
+        let queens = self.piece(by, QUEEN);
+
+        // a pawn of color `by` attacks the square if a pawn of the other color standing
+        // there would attack it back
+        (PAWN_ATTACKS[(1 - by) as usize][square as usize] & self.piece(by, PAWN))
+            | (KNIGHT_ATTACKS[square as usize] & self.piece(by, KNIGHT))
+            | (KING_ATTACKS[square as usize] & self.piece(by, KING))
+            | (rook_attacks(square, self.occupied) & (self.piece(by, ROOK) | queens))
+            | (bishop_attacks(square, self.occupied) & (self.piece(by, BISHOP) | queens))
+    }
+
+    pub fn is_attacked(&self, square: Square, by: u8) -> bool {
+        self.attackers(square, by) != 0
+    }
+
+    /// Whether the king of `color` is attacked, a missing king counts as in check.
+    pub fn is_in_check(&self, color: u8) -> bool {
+        let king = self.piece(color, KING);
+        king == 0 || self.is_attacked(king.trailing_zeros() as Square, 1 - color)
+    }
+}
@@ -0,0 +1,169 @@
+/// One byte per square, `0` is a8 and `63` is h1.
+pub type Board = [u8; 64];
+pub type Square = u8;
+
+pub const WHITE: u8 = 0;
+pub const BLACK: u8 = 1;
+
+// piece kinds, a black piece is its kind + 7
+pub const KING: u8 = 0;
+pub const QUEEN: u8 = 1;
+pub const ROOK: u8 = 2;
+pub const BISHOP: u8 = 3;
+pub const KNIGHT: u8 = 4;
+pub const PAWN: u8 = 5;
+pub const EMPTY: u8 = 6;
+
+pub const CASTLE_WHITE_KING_SIDE: u8 = 1;
+pub const CASTLE_WHITE_QUEEN_SIDE: u8 = 2;
+pub const CASTLE_BLACK_KING_SIDE: u8 = 4;
+pub const CASTLE_BLACK_QUEEN_SIDE: u8 = 8;
+pub const CASTLE_ALL: u8 = 15;
+
+/// The files the queen side and king side rooks castle from in standard chess.
+pub const STANDARD_CASTLING_FILES: [u8; 2] = [0, 7];
+
+pub const START_BOARD: Board = [
+    9, 11, 10, 8, 7, 10, 11, 9, 12, 12, 12, 12, 12, 12, 12, 12, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 5, 5, 5, 5, 5, 5, 5, 5, 2, 4, 3, 1,
+    0, 3, 4, 2,
+];
+
+/// The right to castle on one side.
+pub const fn castling_right(color: u8, king_side: bool) -> u8 {
+    match (color == WHITE, king_side) {
+        (true, true) => CASTLE_WHITE_KING_SIDE,
+        (true, false) => CASTLE_WHITE_QUEEN_SIDE,
+        (false, true) => CASTLE_BLACK_KING_SIDE,
+        (false, false) => CASTLE_BLACK_QUEEN_SIDE,
+    }
+}
+
+pub const fn make_piece(color: u8, kind: u8) -> u8 {
+    kind + 7 * color
+}
+
+pub const fn kind_of(piece: u8) -> u8 {
+    piece % 7
+}
+
+pub const fn color_of(piece: u8) -> Option<u8> {
+    if piece < EMPTY {
+        Some(WHITE)
+    } else if piece > EMPTY && piece <= make_piece(BLACK, PAWN) {
+        Some(BLACK)
+    } else {
+        None
+    }
+}
+
+pub const fn x_of(square: Square) -> u8 {
+    square % 8
+}
+
+pub const fn y_of(square: Square) -> u8 {
+    square / 8
+}
+
+/// The square at the given coordinates, if it is on the board.
+pub const fn square_at(x: i8, y: i8) -> Option<Square> {
+    if x >= 0 && x < 8 && y >= 0 && y < 8 {
+        Some((x + y * 8) as Square)
+    } else {
+        None
+    }
+}
+
+/// The row pawns of this color promote on.
+pub const fn last_row(color: u8) -> u8 {
+    if color == WHITE {
+        0
+    } else {
+        7
+    }
+}
+
+/// The row pawns of this color start on.
+pub const fn pawn_row(color: u8) -> u8 {
+    if color == WHITE {
+        6
+    } else {
+        1
+    }
+}
+
+/// `-1` for white, whose pawns walk towards row 0, and `1` for black.
+pub const fn pawn_direction(color: u8) -> i8 {
+    if color == WHITE {
+        -1
+    } else {
+        1
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Position {
+    pub board: Board,
+    pub side_to_move: u8,
+    pub castling: u8,
+    /// The files of the queen side and king side rooks that may castle, which only differ
+    /// from `STANDARD_CASTLING_FILES` in Chess960.
+    pub castling_files: [u8; 2],
+    pub en_passant: Option<Square>,
+}
+
+impl Position {
+    pub const fn start() -> Self {
+        Position {
+            board: START_BOARD,
+            side_to_move: WHITE,
+            castling: CASTLE_ALL,
+            castling_files: STANDARD_CASTLING_FILES,
+            en_passant: None,
+        }
+    }
+
+    /// A position without castling or en passant rights, which is how the contract plays.
+    pub const fn from_board(board: Board, side_to_move: u8) -> Self {
+        Position {
+            board,
+            side_to_move,
+            castling: 0,
+            castling_files: STANDARD_CASTLING_FILES,
+            en_passant: None,
+        }
+    }
+
+    pub fn king_square(&self, color: u8) -> Option<Square> {
+        let king = make_piece(color, KING);
+        self.board
+            .iter()
+            .position(|&piece| piece == king)
+            .map(|square| square as Square)
+    }
+}
+
+/// A board in 32 bytes, two squares per byte with the lower square in the low nibble.
+pub type PackedBoard = [u8; 32];
+
+pub fn pack_board(board: &Board) -> PackedBoard {
+    let mut packed = [0; 32];
+    for (i, pair) in board.chunks_exact(2).enumerate() {
+        packed[i] = (pair[0] & 0x0f) | (pair[1] << 4);
+    }
+    packed
+}
+
+pub fn unpack_board(packed: &PackedBoard) -> Board {
+    let mut board = [EMPTY; 64];
+    for (i, &byte) in packed.iter().enumerate() {
+        board[2 * i] = byte & 0x0f;
+        board[2 * i + 1] = byte >> 4;
+    }
+    board
+}
+
+/// The piece on one square of a packed board, without unpacking the rest.
+pub const fn packed_piece(packed: &PackedBoard, square: Square) -> u8 {
+    (packed[(square / 2) as usize] >> (4 * (square % 2))) & 0x0f
+}
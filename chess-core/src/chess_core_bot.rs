@@ -0,0 +1,32 @@
+use crate::{chess_core_board::*, chess_core_movegen::*, chess_core_moves::*};
+
+/// What each kind is worth to the practice bot, in pawns; kings are never captured.
+pub const PIECE_VALUES: [u8; 6] = [0, 9, 5, 3, 3, 1];
+
+/// The material a move wins: the piece it captures, plus what a pawn gains by promoting.
+pub fn material_gain(position: &Position, mv: Move) -> u8 {
+    let target = position.board[mv.to as usize];
+    let captured = if color_of(target) == Some(1 - position.side_to_move) {
+        PIECE_VALUES[kind_of(target) as usize]
+    } else {
+        0
+    };
+    let promoted = match mv.promotion {
+        Some(kind) => PIECE_VALUES[kind as usize] - PIECE_VALUES[PAWN as usize],
+        None => 0,
+    };
+    captured + promoted
+}
+
+/// The practice bot's reply: the legal move winning the most material, the first one the
+/// move generator finds among equals, or `None` if the side to move has no legal move.
+pub fn greedy_move(position: &Position) -> Option<Move> {
+    let mut best: Option<(Move, u8)> = None;
+    for &mv in legal_moves(position).iter() {
+        let gain = material_gain(position, mv);
+        if best.is_none_or(|(_, best_gain)| gain > best_gain) {
+            best = Some((mv, gain));
+        }
+    }
+    best.map(|(mv, _)| mv)
+}
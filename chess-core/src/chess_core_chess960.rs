@@ -0,0 +1,87 @@
+use crate::chess_core_board::*;
+
+/// The number of Chess960 start positions.
+pub const CHESS960_POSITIONS: u16 = 960;
+
+/// The Chess960 number of the standard start position.
+pub const STANDARD_CHESS960_INDEX: u16 = 518;
+
+// the two files, out of the five left, that the knights go on
+const KNIGHT_FILES: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
+/// The back row of Chess960 start position `index`, numbered from `0` to `959` the way
+/// Scharnagl does: bishops on squares of both colors, the queen, the knights, and then
+/// the king between the two rooks on the files left.
+pub fn chess960_back_row(index: u16) -> [u8; 8] {
+    let mut row = [EMPTY; 8];
+    let mut n = (index % CHESS960_POSITIONS) as usize;
+
+    row[2 * (n % 4) + 1] = BISHOP;
+    n /= 4;
+    row[2 * (n % 4)] = BISHOP;
+    n /= 4;
+    place_on_free_file(&mut row, n % 6, QUEEN);
+    n /= 6;
+    let (first, second) = KNIGHT_FILES[n];
+    // the second knight counts the free files after the first one is placed
+    place_on_free_file(&mut row, first, KNIGHT);
+    place_on_free_file(&mut row, second - 1, KNIGHT);
+
+    for kind in [ROOK, KING, ROOK] {
+        place_on_free_file(&mut row, 0, kind);
+    }
+    row
+}
+
+fn place_on_free_file(row: &mut [u8; 8], free_index: usize, kind: u8) {
+    if let Some(square) = row
+        .iter_mut()
+        .filter(|piece| **piece == EMPTY)
+        .nth(free_index)
+    {
+        *square = kind;
+    }
+}
+
+/// The board of Chess960 start position `index`, with black's pieces mirroring white's.
+pub fn chess960_board(index: u16) -> Board {
+    let back_row = chess960_back_row(index);
+    let mut board = [EMPTY; 64];
+    for (x, &kind) in back_row.iter().enumerate() {
+        board[x] = make_piece(BLACK, kind);
+        board[x + 8] = make_piece(BLACK, PAWN);
+        board[x + 48] = make_piece(WHITE, PAWN);
+        board[x + 56] = make_piece(WHITE, kind);
+    }
+    board
+}
+
+/// The files of the queen side and king side rooks of Chess960 start position `index`.
+pub fn chess960_castling_files(index: u16) -> [u8; 2] {
+    let back_row = chess960_back_row(index);
+    let mut rooks = (0..8u8).filter(|&x| back_row[x as usize] == ROOK);
+    let queen_side = rooks.next().unwrap_or(0);
+    [queen_side, rooks.next().unwrap_or(7)]
+}
+
+/// Chess960 start position `index` with white to move and every castling right.
+pub fn chess960_position(index: u16) -> Position {
+    Position {
+        board: chess960_board(index),
+        side_to_move: WHITE,
+        castling: CASTLE_ALL,
+        castling_files: chess960_castling_files(index),
+        en_passant: None,
+    }
+}
@@ -0,0 +1,206 @@
+use core::fmt::{self, Write};
+
+use crate::chess_core_board::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FenError {
+    InvalidBoard,
+    InvalidSideToMove,
+    InvalidCastling,
+    InvalidEnPassant,
+}
+
+const PIECE_LETTERS: &[u8; 6] = b"KQRBNP";
+
+/// The letter FEN uses for a piece, uppercase for white.
+pub const fn piece_letter(piece: u8) -> u8 {
+    let letter = PIECE_LETTERS[kind_of(piece) as usize];
+    if piece > EMPTY {
+        letter.to_ascii_lowercase()
+    } else {
+        letter
+    }
+}
+
+/// The piece written as `letter` in FEN.
+pub fn piece_from_letter(letter: u8) -> Option<u8> {
+    let kind = PIECE_LETTERS
+        .iter()
+        .position(|&l| l == letter.to_ascii_uppercase())? as u8;
+    let color = if letter.is_ascii_uppercase() {
+        WHITE
+    } else {
+        BLACK
+    };
+    Some(make_piece(color, kind))
+}
+
+/// Parses a square such as `e4`.
+pub fn parse_square(name: &[u8]) -> Option<Square> {
+    match name {
+        [file @ b'a'..=b'h', rank @ b'1'..=b'8'] => Some((file - b'a') + (b'8' - rank) * 8),
+        _ => None,
+    }
+}
+
+/// Writes a position as FEN, naming the file of the castling rook as in `HAha` when the
+/// rooks do not castle from the corners. The move counters are not kept, they start over
+/// at `0 1`.
+pub fn write_fen<W: Write>(out: &mut W, position: &Position) -> fmt::Result {
+    for (y, row) in position.board.chunks(8).enumerate() {
+        if y > 0 {
+            out.write_char('/')?;
+        }
+        let mut empty = 0;
+        for &piece in row {
+            if piece == EMPTY {
+                empty += 1;
+                continue;
+            }
+            if empty > 0 {
+                write!(out, "{empty}")?;
+                empty = 0;
+            }
+            out.write_char(piece_letter(piece) as char)?;
+        }
+        if empty > 0 {
+            write!(out, "{empty}")?;
+        }
+    }
+
+    out.write_str(if position.side_to_move == WHITE {
+        " w "
+    } else {
+        " b "
+    })?;
+
+    if position.castling == 0 {
+        out.write_char('-')?;
+    }
+    for (color, king_side) in [(WHITE, true), (WHITE, false), (BLACK, true), (BLACK, false)] {
+        if position.castling & castling_right(color, king_side) == 0 {
+            continue;
+        }
+        let letter = match (
+            position.castling_files == STANDARD_CASTLING_FILES,
+            king_side,
+        ) {
+            (true, true) => b'K',
+            (true, false) => b'Q',
+            (false, _) => b'A' + position.castling_files[king_side as usize],
+        };
+        let letter = if color == WHITE {
+            letter
+        } else {
+            letter.to_ascii_lowercase()
+        };
+        out.write_char(letter as char)?;
+    }
+
+    out.write_char(' ')?;
+    match position.en_passant {
+        Some(square) => {
+            out.write_char((b'a' + x_of(square)) as char)?;
+            out.write_char((b'8' - y_of(square)) as char)?;
+        }
+        None => out.write_char('-')?,
+    }
+    out.write_str(" 0 1")
+}
+
+/// Reads the board, side to move, castling and en passant fields of a FEN string.
+/// The move counters are optional and ignored.
+pub fn parse_fen(fen: &str) -> Result<Position, FenError> {
+    let mut fields = fen.split_ascii_whitespace();
+
+    let board = parse_board(fields.next().ok_or(FenError::InvalidBoard)?)?;
+
+    let side_to_move = match fields.next() {
+        Some("w") => WHITE,
+        Some("b") => BLACK,
+        _ => return Err(FenError::InvalidSideToMove),
+    };
+
+    let mut castling = 0;
+    let mut castling_files = STANDARD_CASTLING_FILES;
+    match fields.next() {
+        Some("-") => {}
+        Some(rights) => {
+            for right in rights.bytes() {
+                castling |= match right {
+                    b'K' => CASTLE_WHITE_KING_SIDE,
+                    b'Q' => CASTLE_WHITE_QUEEN_SIDE,
+                    b'k' => CASTLE_BLACK_KING_SIDE,
+                    b'q' => CASTLE_BLACK_QUEEN_SIDE,
+                    // Chess960 names the file of the rook instead, as in `HAha`
+                    b'A'..=b'H' | b'a'..=b'h' => {
+                        let color = if right.is_ascii_uppercase() {
+                            WHITE
+                        } else {
+                            BLACK
+                        };
+                        let file = right.to_ascii_lowercase() - b'a';
+                        let king_file = board
+                            .iter()
+                            .position(|&piece| piece == make_piece(color, KING))
+                            .ok_or(FenError::InvalidCastling)?
+                            as u8
+                            % 8;
+                        let king_side = file > king_file;
+                        castling_files[king_side as usize] = file;
+                        castling_right(color, king_side)
+                    }
+                    _ => return Err(FenError::InvalidCastling),
+                };
+            }
+        }
+        None => return Err(FenError::InvalidCastling),
+    }
+
+    let en_passant = match fields.next() {
+        Some("-") => None,
+        Some(square) => Some(parse_square(square.as_bytes()).ok_or(FenError::InvalidEnPassant)?),
+        None => return Err(FenError::InvalidEnPassant),
+    };
+
+    Ok(Position {
+        board,
+        side_to_move,
+        castling,
+        castling_files,
+        en_passant,
+    })
+}
+
+fn parse_board(field: &str) -> Result<Board, FenError> {
+    let mut board = [EMPTY; 64];
+    let mut rows = 0;
+
+    for (y, row) in field.split('/').enumerate() {
+        if y >= 8 {
+            return Err(FenError::InvalidBoard);
+        }
+        let mut x = 0;
+        for letter in row.bytes() {
+            if let b'1'..=b'8' = letter {
+                x += (letter - b'0') as usize;
+            } else {
+                let piece = piece_from_letter(letter).ok_or(FenError::InvalidBoard)?;
+                if x >= 8 {
+                    return Err(FenError::InvalidBoard);
+                }
+                board[x + y * 8] = piece;
+                x += 1;
+            }
+        }
+        if x != 8 {
+            return Err(FenError::InvalidBoard);
+        }
+        rows += 1;
+    }
+
+    if rows != 8 {
+        return Err(FenError::InvalidBoard);
+    }
+    Ok(board)
+}
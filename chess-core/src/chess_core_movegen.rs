@@ -0,0 +1,173 @@
+use crate::{
+    chess_core_attacks::*, chess_core_bitboards::*, chess_core_board::*, chess_core_moves::*,
+};
+
+// no position has more than 218 legal moves
+const MAX_MOVES: usize = 256;
+
+const PROMOTIONS: [u8; 4] = [QUEEN, ROOK, BISHOP, KNIGHT];
+
+/// A fixed capacity list of moves, so that no allocator is needed.
+#[derive(Clone)]
+pub struct MoveList {
+    moves: [Move; MAX_MOVES],
+    len: usize,
+}
+
+impl MoveList {
+    pub const fn new() -> Self {
+        MoveList {
+            moves: [Move::new(0, 0); MAX_MOVES],
+            len: 0,
+        }
+    }
+
+    pub fn push(&mut self, mv: Move) {
+        self.moves[self.len] = mv;
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_slice(&self) -> &[Move] {
+        &self.moves[..self.len]
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Move> {
+        self.as_slice().iter()
+    }
+
+    pub fn contains(&self, mv: &Move) -> bool {
+        self.as_slice().contains(mv)
+    }
+}
+
+impl Default for MoveList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Every move the side to move can make, including those that leave its king in check.
+pub fn pseudo_legal_moves(position: &Position) -> MoveList {
+    let mut moves = MoveList::new();
+    let color = position.side_to_move;
+    let bitboards = Bitboards::from_board(&position.board);
+    let not_own = !bitboards.color(color);
+
+    let mut own = bitboards.color(color);
+    while own != 0 {
+        let from = own.trailing_zeros() as Square;
+        own &= own - 1;
+
+        let targets = match kind_of(position.board[from as usize]) {
+            PAWN => {
+                push_pawn_moves(position, from, &mut moves);
+                continue;
+            }
+            KING => {
+                push_castling_moves(position, &mut moves);
+                KING_ATTACKS[from as usize]
+            }
+            KNIGHT => KNIGHT_ATTACKS[from as usize],
+            BISHOP => bishop_attacks(from, bitboards.occupied),
+            ROOK => rook_attacks(from, bitboards.occupied),
+            _ => bishop_attacks(from, bitboards.occupied) | rook_attacks(from, bitboards.occupied),
+        };
+
+        let mut targets = targets & not_own;
+        while targets != 0 {
+            moves.push(Move::new(from, targets.trailing_zeros() as Square));
+            targets &= targets - 1;
+        }
+    }
+
+    moves
+}
+
+/// Every move the side to move can make without leaving its king in check.
+pub fn legal_moves(position: &Position) -> MoveList {
+    let mut moves = MoveList::new();
+    for &mv in pseudo_legal_moves(position).iter() {
+        if !is_in_check(&play(position, mv).board, position.side_to_move) {
+            moves.push(mv);
+        }
+    }
+    moves
+}
+
+/// Counts the leaf positions `depth` moves deep, to compare against known move counts.
+pub fn perft(position: &Position, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves = legal_moves(position);
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+
+    moves
+        .iter()
+        .map(|&mv| perft(&play(position, mv), depth - 1))
+        .sum()
+}
+
+fn push_pawn_moves(position: &Position, from: Square, moves: &mut MoveList) {
+    let board = &position.board;
+    let color = position.side_to_move;
+    let direction = pawn_direction(color);
+    let x = x_of(from) as i8;
+    let y = y_of(from) as i8;
+
+    if let Some(to) = square_at(x, y + direction) {
+        if board[to as usize] == EMPTY {
+            push_pawn_move(color, from, to, moves);
+
+            if y_of(from) == pawn_row(color) {
+                let to = (to as i8 + 8 * direction) as Square;
+                if board[to as usize] == EMPTY {
+                    moves.push(Move::new(from, to));
+                }
+            }
+        }
+    }
+
+    for dx in [-1, 1] {
+        if let Some(to) = square_at(x + dx, y + direction) {
+            let target = board[to as usize];
+            if color_of(target) == Some(1 - color) || position.en_passant == Some(to) {
+                push_pawn_move(color, from, to, moves);
+            }
+        }
+    }
+}
+
+fn push_pawn_move(color: u8, from: Square, to: Square, moves: &mut MoveList) {
+    if y_of(to) == last_row(color) {
+        for kind in PROMOTIONS {
+            moves.push(Move::with_promotion(from, to, kind));
+        }
+    } else {
+        moves.push(Move::new(from, to));
+    }
+}
+
+fn push_castling_moves(position: &Position, moves: &mut MoveList) {
+    if position.castling == 0 {
+        return;
+    }
+    for king_side in [false, true] {
+        if let Some(mv) = castling_move(position, king_side) {
+            if check_pseudo_legal(position, mv).is_ok() {
+                moves.push(mv);
+            }
+        }
+    }
+}
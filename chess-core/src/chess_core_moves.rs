@@ -0,0 +1,392 @@
+use crate::{chess_core_attacks::*, chess_core_board::*};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Move {
+    pub from: Square,
+    pub to: Square,
+    /// The kind a pawn turns into on the last row, `None` for every other move.
+    pub promotion: Option<u8>,
+}
+
+impl Move {
+    pub const fn new(from: Square, to: Square) -> Self {
+        Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    pub const fn with_promotion(from: Square, to: Square, kind: u8) -> Self {
+        Move {
+            from,
+            to,
+            promotion: Some(kind),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MoveError {
+    NotOnBoard,
+    NothingMoved,
+    NotYourPiece,
+    InvalidKingMove,
+    InvalidQueenMove,
+    InvalidRookMove,
+    InvalidBishopMove,
+    InvalidKnightMove,
+    InvalidPawnMove,
+    InvalidPromotion,
+    KingLeftInCheck,
+    InvalidDrop,
+    InvalidPawnDrop,
+    SquareNotEmpty,
+}
+
+impl MoveError {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            MoveError::NotOnBoard => "Move is not on the board",
+            MoveError::NothingMoved => "You did not move anything",
+            MoveError::NotYourPiece => "Invalid move",
+            MoveError::InvalidKingMove => "Invalid king move",
+            MoveError::InvalidQueenMove => "Invalid queen move",
+            MoveError::InvalidRookMove => "Invalid rook move",
+            MoveError::InvalidBishopMove => "Invalid bishop move",
+            MoveError::InvalidKnightMove => "Invalid knight move",
+            MoveError::InvalidPawnMove => "Invalid pawn move",
+            MoveError::InvalidPromotion => "Invalid promotion",
+            MoveError::KingLeftInCheck => "King is left in check",
+            MoveError::InvalidDrop => "Invalid drop",
+            MoveError::InvalidPawnDrop => "Pawns cannot be dropped on the first or last rank",
+            MoveError::SquareNotEmpty => "Square is not empty",
+        }
+    }
+}
+
+/// Checks that the piece can go there, without looking at whether the own king is
+/// left in check. The contract leaves that to the opponent through `callIllegalMove`.
+pub fn check_pseudo_legal(position: &Position, mv: Move) -> Result<(), MoveError> {
+    if mv.from >= 64 || mv.to >= 64 {
+        return Err(MoveError::NotOnBoard);
+    }
+    if mv.from == mv.to {
+        return Err(MoveError::NothingMoved);
+    }
+
+    let board = &position.board;
+    let color = position.side_to_move;
+    let piece = board[mv.from as usize];
+    let target = board[mv.to as usize];
+
+    if color_of(piece) != Some(color) {
+        return Err(MoveError::NotYourPiece);
+    }
+    if let Some(castling) = castling_of(position, mv) {
+        return check_castling(position, mv, castling);
+    }
+    if color_of(target) == Some(color) {
+        return Err(MoveError::NotYourPiece);
+    }
+
+    let dx = x_of(mv.to) as i8 - x_of(mv.from) as i8;
+    let dy = y_of(mv.to) as i8 - y_of(mv.from) as i8;
+
+    let kind = kind_of(piece);
+    if kind == PAWN {
+        check_pawn_move(position, mv, dx, dy)?;
+        return check_promotion(mv, color, true);
+    }
+    check_promotion(mv, color, false)?;
+
+    match kind {
+        KING => {
+            if dx.abs() <= 1 && dy.abs() <= 1 {
+                Ok(())
+            } else {
+                Err(MoveError::InvalidKingMove)
+            }
+        }
+        QUEEN => {
+            if (dx == 0 || dy == 0 || dx.abs() == dy.abs()) && is_path_clear(board, mv, dx, dy) {
+                Ok(())
+            } else {
+                Err(MoveError::InvalidQueenMove)
+            }
+        }
+        ROOK => {
+            if (dx == 0 || dy == 0) && is_path_clear(board, mv, dx, dy) {
+                Ok(())
+            } else {
+                Err(MoveError::InvalidRookMove)
+            }
+        }
+        BISHOP => {
+            if dx.abs() == dy.abs() && is_path_clear(board, mv, dx, dy) {
+                Ok(())
+            } else {
+                Err(MoveError::InvalidBishopMove)
+            }
+        }
+        _ => {
+            if dx.abs() + dy.abs() == 3 && dx != 0 && dy != 0 {
+                Ok(())
+            } else {
+                Err(MoveError::InvalidKnightMove)
+            }
+        }
+    }
+}
+
+/// Same as `check_pseudo_legal`, and the own king must not be left in check.
+pub fn check_legal(position: &Position, mv: Move) -> Result<(), MoveError> {
+    check_pseudo_legal(position, mv)?;
+    if is_in_check(&play(position, mv).board, position.side_to_move) {
+        return Err(MoveError::KingLeftInCheck);
+    }
+    Ok(())
+}
+
+fn check_pawn_move(position: &Position, mv: Move, dx: i8, dy: i8) -> Result<(), MoveError> {
+    let board = &position.board;
+    let color = position.side_to_move;
+    let direction = pawn_direction(color);
+    let target = board[mv.to as usize];
+
+    let is_valid = if dx == 0 && dy == direction {
+        target == EMPTY
+    } else if dx == 0 && dy == 2 * direction {
+        y_of(mv.from) == pawn_row(color)
+            && target == EMPTY
+            && board[(mv.from as i8 + 8 * direction) as usize] == EMPTY
+    } else if dx.abs() == 1 && dy == direction {
+        target != EMPTY || position.en_passant == Some(mv.to)
+    } else {
+        false
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(MoveError::InvalidPawnMove)
+    }
+}
+
+fn check_promotion(mv: Move, color: u8, is_pawn: bool) -> Result<(), MoveError> {
+    let must_promote = is_pawn && y_of(mv.to) == last_row(color);
+    match mv.promotion {
+        None if !must_promote => Ok(()),
+        Some(kind) if must_promote && (QUEEN..=KNIGHT).contains(&kind) => Ok(()),
+        _ => Err(MoveError::InvalidPromotion),
+    }
+}
+
+// where the rook castles from, and where the king and the rook end up
+#[derive(Clone, Copy)]
+struct Castling {
+    right: u8,
+    rook_from: Square,
+    king_to: Square,
+    rook_to: Square,
+}
+
+// a king move castles when the king goes onto its own castling rook, as in Chess960, or
+// two squares sideways from the e file while the rooks castle from the a and h files
+fn castling_of(position: &Position, mv: Move) -> Option<Castling> {
+    let board = &position.board;
+    let color = position.side_to_move;
+    let row = last_row(1 - color);
+    if mv.from >= 64
+        || mv.to >= 64
+        || board[mv.from as usize] != make_piece(color, KING)
+        || y_of(mv.from) != row
+        || y_of(mv.to) != row
+    {
+        return None;
+    }
+
+    let king_x = x_of(mv.from);
+    let rook_x = if board[mv.to as usize] == make_piece(color, ROOK) {
+        x_of(mv.to)
+    } else if position.castling_files == STANDARD_CASTLING_FILES
+        && king_x == 4
+        && x_of(mv.to).abs_diff(4) == 2
+    {
+        if x_of(mv.to) == 6 {
+            7
+        } else {
+            0
+        }
+    } else {
+        return None;
+    };
+
+    let king_side = rook_x > king_x;
+    if rook_x != position.castling_files[king_side as usize] {
+        return None;
+    }
+
+    // the king and the rook end on the squares they would in standard chess
+    let (king_to_x, rook_to_x) = if king_side { (6, 5) } else { (2, 3) };
+    Some(Castling {
+        right: castling_right(color, king_side),
+        rook_from: rook_x + row * 8,
+        king_to: king_to_x + row * 8,
+        rook_to: rook_to_x + row * 8,
+    })
+}
+
+/// Whether the move castles, see `castling_move` for how castling moves look.
+pub fn is_castling(position: &Position, mv: Move) -> bool {
+    castling_of(position, mv).is_some()
+}
+
+/// The move castling on one side for the side to move, whether or not it may castle,
+/// or `None` when the king or the rook are not in place.
+///
+/// With the rooks on the a and h files and the king on the e file, the king moves two
+/// squares sideways. Otherwise, as Chess960 positions can have the king next to its
+/// destination, the king moves onto the rook it castles with.
+pub fn castling_move(position: &Position, king_side: bool) -> Option<Move> {
+    let color = position.side_to_move;
+    let king = position.king_square(color)?;
+    let row = last_row(1 - color);
+    if y_of(king) != row {
+        return None;
+    }
+
+    let mv = if position.castling_files == STANDARD_CASTLING_FILES && x_of(king) == 4 {
+        Move::new(king, if king_side { king + 2 } else { king - 2 })
+    } else {
+        Move::new(king, position.castling_files[king_side as usize] + row * 8)
+    };
+    is_castling(position, mv).then_some(mv)
+}
+
+fn check_castling(position: &Position, mv: Move, castling: Castling) -> Result<(), MoveError> {
+    let board = &position.board;
+    let color = position.side_to_move;
+
+    // every square the king and the rook cross or land on is free, except for themselves
+    let squares = [
+        mv.from,
+        castling.rook_from,
+        castling.king_to,
+        castling.rook_to,
+    ];
+    let first = squares.iter().min().copied().unwrap_or_default();
+    let last = squares.iter().max().copied().unwrap_or_default();
+    let is_path_free = (first..=last).all(|square| {
+        board[square as usize] == EMPTY || square == mv.from || square == castling.rook_from
+    });
+
+    // the king may not castle out of or through check, landing in check is left to
+    // the usual rules
+    let step: i8 = if castling.king_to > mv.from { 1 } else { -1 };
+    let mut is_path_safe = !is_square_attacked(board, mv.from, 1 - color);
+    let mut square = mv.from as i8;
+    while square != castling.king_to as i8 && is_path_safe {
+        is_path_safe = !is_square_attacked(board, square as Square, 1 - color);
+        square += step;
+    }
+
+    let is_valid = position.castling & castling.right != 0
+        && board[castling.rook_from as usize] == make_piece(color, ROOK)
+        && is_path_free
+        && is_path_safe;
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(MoveError::InvalidKingMove)
+    }
+}
+
+fn is_path_clear(board: &Board, mv: Move, dx: i8, dy: i8) -> bool {
+    let step = dx.signum() + dy.signum() * 8;
+    let mut square = mv.from as i8 + step;
+    while square != mv.to as i8 {
+        if board[square as usize] != EMPTY {
+            return false;
+        }
+        square += step;
+    }
+    true
+}
+
+/// Whether the piece moves along its lines, but another piece stands in its way.
+pub fn is_path_blocked(board: &Board, mv: Move) -> bool {
+    if mv.from >= 64 || mv.to >= 64 || mv.from == mv.to {
+        return false;
+    }
+
+    let piece = board[mv.from as usize];
+    let dx = x_of(mv.to) as i8 - x_of(mv.from) as i8;
+    let dy = y_of(mv.to) as i8 - y_of(mv.from) as i8;
+
+    let along_its_lines = match (kind_of(piece), color_of(piece)) {
+        (QUEEN, _) => dx == 0 || dy == 0 || dx.abs() == dy.abs(),
+        (ROOK, _) => dx == 0 || dy == 0,
+        (BISHOP, _) => dx.abs() == dy.abs(),
+        (PAWN, Some(color)) => {
+            dx == 0 && dy == 2 * pawn_direction(color) && y_of(mv.from) == pawn_row(color)
+        }
+        _ => false,
+    };
+    along_its_lines && !is_path_clear(board, mv, dx, dy)
+}
+
+/// Plays a move that was already checked, and returns the resulting position.
+pub fn play(position: &Position, mv: Move) -> Position {
+    let mut next = *position;
+    let board = &mut next.board;
+    let color = position.side_to_move;
+    let piece = board[mv.from as usize];
+    let kind = kind_of(piece);
+
+    if kind == PAWN && x_of(mv.from) != x_of(mv.to) && board[mv.to as usize] == EMPTY {
+        // en passant, the captured pawn stands next to the origin square
+        board[(y_of(mv.from) * 8 + x_of(mv.to)) as usize] = EMPTY;
+    }
+    next.castling &=
+        !(castling_rights_lost(position, mv.from) | castling_rights_lost(position, mv.to));
+
+    if let Some(castling) = castling_of(position, mv) {
+        let rook = board[castling.rook_from as usize];
+        board[mv.from as usize] = EMPTY;
+        board[castling.rook_from as usize] = EMPTY;
+        board[castling.king_to as usize] = piece;
+        board[castling.rook_to as usize] = rook;
+    } else {
+        board[mv.to as usize] = match mv.promotion {
+            Some(promotion) => make_piece(color, promotion),
+            None => piece,
+        };
+        board[mv.from as usize] = EMPTY;
+    }
+
+    next.en_passant = if kind == PAWN && y_of(mv.from).abs_diff(y_of(mv.to)) == 2 {
+        Some((mv.from + mv.to) / 2)
+    } else {
+        None
+    };
+    next.side_to_move = 1 - color;
+    next
+}
+
+// moving the king, or moving from or capturing on the square of a castling rook, ends
+// some castling rights
+fn castling_rights_lost(position: &Position, square: Square) -> u8 {
+    let mut lost = 0;
+    for color in [WHITE, BLACK] {
+        let row = last_row(1 - color);
+        for king_side in [false, true] {
+            let rook = position.castling_files[king_side as usize] + row * 8;
+            if square == rook || position.board[square as usize] == make_piece(color, KING) {
+                lost |= castling_right(color, king_side);
+            }
+        }
+    }
+    lost
+}
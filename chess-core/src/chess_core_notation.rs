@@ -0,0 +1,130 @@
+use crate::{chess_core_board::*, chess_core_fen::*, chess_core_movegen::*, chess_core_moves::*};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NotationError {
+    InvalidNotation,
+    NoSuchMove,
+    AmbiguousMove,
+}
+
+impl NotationError {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            NotationError::InvalidNotation => "Invalid move notation",
+            NotationError::NoSuchMove => "No piece can make this move",
+            NotationError::AmbiguousMove => "Ambiguous move",
+        }
+    }
+}
+
+fn promotion_from_letter(letter: u8) -> Option<u8> {
+    match letter.to_ascii_lowercase() {
+        b'q' => Some(QUEEN),
+        b'r' => Some(ROOK),
+        b'b' => Some(BISHOP),
+        b'n' => Some(KNIGHT),
+        _ => None,
+    }
+}
+
+/// Parses a move in UCI notation, such as `e2e4` or `e7e8q`.
+pub fn parse_uci(notation: &[u8]) -> Result<Move, NotationError> {
+    if notation.len() != 4 && notation.len() != 5 {
+        return Err(NotationError::InvalidNotation);
+    }
+
+    let from = parse_square(&notation[..2]).ok_or(NotationError::InvalidNotation)?;
+    let to = parse_square(&notation[2..4]).ok_or(NotationError::InvalidNotation)?;
+    let promotion = match notation.get(4) {
+        Some(&letter) => Some(promotion_from_letter(letter).ok_or(NotationError::InvalidNotation)?),
+        None => None,
+    };
+
+    Ok(Move {
+        from,
+        to,
+        promotion,
+    })
+}
+
+/// Parses a move in SAN, such as `Nf3`, `exd5`, `e8=Q` or `O-O`, and finds the move it
+/// stands for in the position. Legal moves are preferred, moves leaving the king in check
+/// are only considered when no legal move matches.
+pub fn parse_san(position: &Position, notation: &[u8]) -> Result<Move, NotationError> {
+    let mut notation = notation;
+    while let [rest @ .., b'+' | b'#' | b'!' | b'?'] = notation {
+        notation = rest;
+    }
+
+    let castling_side = match notation {
+        b"O-O" | b"0-0" => Some(true),
+        b"O-O-O" | b"0-0-0" => Some(false),
+        _ => None,
+    };
+    if let Some(king_side) = castling_side {
+        return castling_move(position, king_side).ok_or(NotationError::NoSuchMove);
+    }
+
+    let (kind, mut rest) = match notation {
+        [letter @ (b'K' | b'Q' | b'R' | b'B' | b'N'), rest @ ..] => {
+            (kind_of(piece_from_letter(*letter).unwrap_or(EMPTY)), rest)
+        }
+        _ => (PAWN, notation),
+    };
+
+    let mut promotion = None;
+    if kind == PAWN {
+        if let [head @ .., letter] = rest {
+            if let Some(promoted) =
+                promotion_from_letter(*letter).filter(|_| letter.is_ascii_uppercase())
+            {
+                promotion = Some(promoted);
+                rest = head.strip_suffix(b"=").unwrap_or(head);
+            }
+        }
+    }
+
+    if rest.len() < 2 {
+        return Err(NotationError::InvalidNotation);
+    }
+    let (origin, destination) = rest.split_at(rest.len() - 2);
+    let to = parse_square(destination).ok_or(NotationError::InvalidNotation)?;
+    let origin = origin.strip_suffix(b"x").unwrap_or(origin);
+    if promotion.is_none() && kind == PAWN && y_of(to) == last_row(position.side_to_move) {
+        // like `makeMove`, a pawn reaching the last row without a letter becomes a queen
+        promotion = Some(QUEEN);
+    }
+
+    let mut from_x = None;
+    let mut from_y = None;
+    for &letter in origin {
+        match letter {
+            b'a'..=b'h' if from_x.is_none() && from_y.is_none() => from_x = Some(letter - b'a'),
+            b'1'..=b'8' if from_y.is_none() => from_y = Some(b'8' - letter),
+            _ => return Err(NotationError::InvalidNotation),
+        }
+    }
+
+    let matches = |mv: &&Move| {
+        mv.to == to
+            && mv.promotion == promotion
+            && kind_of(position.board[mv.from as usize]) == kind
+            && from_x.is_none_or(|x| x_of(mv.from) == x)
+            && from_y.is_none_or(|y| y_of(mv.from) == y)
+    };
+
+    match find_unique(legal_moves(position).iter().filter(matches)) {
+        Err(NotationError::NoSuchMove) => {
+            find_unique(pseudo_legal_moves(position).iter().filter(matches))
+        }
+        result => result,
+    }
+}
+
+fn find_unique<'a>(mut moves: impl Iterator<Item = &'a Move>) -> Result<Move, NotationError> {
+    match (moves.next(), moves.next()) {
+        (Some(&mv), None) => Ok(mv),
+        (None, _) => Err(NotationError::NoSuchMove),
+        _ => Err(NotationError::AmbiguousMove),
+    }
+}
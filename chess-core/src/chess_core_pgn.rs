@@ -0,0 +1,398 @@
+use core::fmt::{self, Write};
+
+use crate::{
+    chess_core_attacks::*, chess_core_board::*, chess_core_fen::*, chess_core_movegen::*,
+    chess_core_moves::*, chess_core_notation::*,
+};
+
+// export format lines stay under 80 characters
+const MAX_LINE_LEN: usize = 79;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PgnError {
+    Write,
+    /// The move at this index, counting from `0`, cannot be played at all.
+    InvalidMove(usize),
+}
+
+impl From<fmt::Error> for PgnError {
+    fn from(_: fmt::Error) -> Self {
+        PgnError::Write
+    }
+}
+
+/// The seven tags every PGN game starts with, in the order they are written.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PgnTags<'a> {
+    pub event: &'a str,
+    pub site: &'a str,
+    pub date: &'a str,
+    pub round: &'a str,
+    pub white: &'a str,
+    pub black: &'a str,
+    /// `1-0`, `0-1`, `1/2-1/2` or `*`.
+    pub result: &'a str,
+    /// Written after the seven tags, such as `Chess960`, for games with other rules.
+    pub variant: Option<&'a str>,
+}
+
+fn write_square<W: Write>(out: &mut W, square: Square) -> fmt::Result {
+    out.write_char((b'a' + x_of(square)) as char)?;
+    out.write_char((b'8' - y_of(square)) as char)
+}
+
+/// Writes a move the way SAN does, such as `Nbd7`, `exd5`, `e8=Q+` or `O-O`.
+pub fn write_san<W: Write>(out: &mut W, position: &Position, mv: Move) -> fmt::Result {
+    let board = &position.board;
+    let piece = board[mv.from as usize];
+    let kind = kind_of(piece);
+
+    if is_castling(position, mv) {
+        let king_side = x_of(mv.to) > x_of(mv.from);
+        out.write_str(if king_side { "O-O" } else { "O-O-O" })?;
+    } else {
+        let is_capture =
+            board[mv.to as usize] != EMPTY || (kind == PAWN && x_of(mv.from) != x_of(mv.to));
+
+        if kind == PAWN {
+            if is_capture {
+                out.write_char((b'a' + x_of(mv.from)) as char)?;
+            }
+        } else {
+            out.write_char(piece_letter(make_piece(WHITE, kind)) as char)?;
+
+            // name the file, the rank or both when another such piece can go there too
+            let (mut same_file, mut same_rank, mut ambiguous) = (false, false, false);
+            for other in legal_moves(position).iter() {
+                if other.to == mv.to && other.from != mv.from && board[other.from as usize] == piece
+                {
+                    ambiguous = true;
+                    same_file |= x_of(other.from) == x_of(mv.from);
+                    same_rank |= y_of(other.from) == y_of(mv.from);
+                }
+            }
+            if ambiguous && (!same_file || same_rank) {
+                out.write_char((b'a' + x_of(mv.from)) as char)?;
+            }
+            if same_file {
+                out.write_char((b'8' - y_of(mv.from)) as char)?;
+            }
+        }
+
+        if is_capture {
+            out.write_char('x')?;
+        }
+        write_square(out, mv.to)?;
+        if let Some(promotion) = mv.promotion {
+            out.write_char('=')?;
+            out.write_char(piece_letter(make_piece(WHITE, promotion)) as char)?;
+        }
+    }
+
+    let next = play(position, mv);
+    if is_in_check(&next.board, next.side_to_move) {
+        out.write_char(if legal_moves(&next).is_empty() {
+            '#'
+        } else {
+            '+'
+        })?;
+    }
+    Ok(())
+}
+
+// a short piece of move text, formatted before it is known whether it fits on the line
+struct Token {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl Token {
+    fn new() -> Self {
+        Token {
+            bytes: [0; 32],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for Token {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// writes space separated tokens, wrapping lines before they get too long
+struct MoveText<'w, W: Write> {
+    out: &'w mut W,
+    line_len: usize,
+}
+
+impl<W: Write> MoveText<'_, W> {
+    fn push(&mut self, token: &str) -> fmt::Result {
+        if self.line_len > 0 && self.line_len + 1 + token.len() > MAX_LINE_LEN {
+            self.out.write_char('\n')?;
+            self.line_len = 0;
+        }
+        if self.line_len > 0 {
+            self.out.write_char(' ')?;
+            self.line_len += 1;
+        }
+        self.out.write_str(token)?;
+        self.line_len += token.len();
+        Ok(())
+    }
+}
+
+/// Writes a game played from `start` as PGN. Games that do not start from the standard
+/// start position, without castling as the contract plays it, get the `SetUp` and `FEN`
+/// tags.
+///
+/// The contract accepts moves that leave the own king in check, PGN readers do not. The
+/// game text stops before such a move and a comment names it instead.
+pub fn write_pgn<W: Write>(
+    out: &mut W,
+    tags: &PgnTags,
+    start: &Position,
+    moves: &[Move],
+) -> Result<(), PgnError> {
+    for (name, value) in [
+        ("Event", tags.event),
+        ("Site", tags.site),
+        ("Date", tags.date),
+        ("Round", tags.round),
+        ("White", tags.white),
+        ("Black", tags.black),
+        ("Result", tags.result),
+    ] {
+        write_tag(out, name, value)?;
+    }
+    if let Some(variant) = tags.variant {
+        write_tag(out, "Variant", variant)?;
+    }
+    if *start != Position::from_board(START_BOARD, WHITE) {
+        write_tag(out, "SetUp", "1")?;
+        out.write_str("[FEN \"")?;
+        write_fen(out, start)?;
+        out.write_str("\"]\n")?;
+    }
+    out.write_char('\n')?;
+
+    let mut text = MoveText { out, line_len: 0 };
+    let mut position = *start;
+    for (index, &mv) in moves.iter().enumerate() {
+        if check_pseudo_legal(&position, mv).is_err() {
+            return Err(PgnError::InvalidMove(index));
+        }
+
+        if check_legal(&position, mv).is_err() {
+            let mut comment = Token::new();
+            comment.write_str("{")?;
+            write_square(&mut comment, mv.from)?;
+            write_square(&mut comment, mv.to)?;
+            text.push(comment.as_str())?;
+            for word in ["left", "the", "king", "in", "check}"] {
+                text.push(word)?;
+            }
+            break;
+        }
+
+        let mut token = Token::new();
+        if position.side_to_move == WHITE {
+            write!(token, "{}.", index / 2 + 1)?;
+            text.push(token.as_str())?;
+            token = Token::new();
+        }
+        write_san(&mut token, &position, mv)?;
+        text.push(token.as_str())?;
+
+        position = play(&position, mv);
+    }
+
+    text.push(tags.result)?;
+    text.out.write_char('\n')?;
+    Ok(())
+}
+
+fn write_tag<W: Write>(out: &mut W, name: &str, value: &str) -> fmt::Result {
+    write!(out, "[{} \"", name)?;
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            out.write_char('\\')?;
+        }
+        out.write_char(c)?;
+    }
+    out.write_str("\"]\n")
+}
+
+/// The SAN moves of a PGN game, skipping the tags, comments, variations, move numbers and
+/// annotation glyphs. It stops at the result.
+pub fn pgn_moves(pgn: &str) -> PgnMoves<'_> {
+    PgnMoves { pgn, index: 0 }
+}
+
+pub struct PgnMoves<'a> {
+    pgn: &'a str,
+    index: usize,
+}
+
+impl PgnMoves<'_> {
+    fn skip_past(&mut self, end: u8) {
+        let bytes = self.pgn.as_bytes();
+        while self.index < bytes.len() && bytes[self.index] != end {
+            self.index += 1;
+        }
+        self.index += 1;
+    }
+
+    fn skip_variation(&mut self) {
+        let bytes = self.pgn.as_bytes();
+        let mut depth = 0;
+        while self.index < bytes.len() {
+            match bytes[self.index] {
+                b'(' => depth += 1,
+                b')' => depth -= 1,
+                b'{' => {
+                    self.skip_past(b'}');
+                    continue;
+                }
+                _ => {}
+            }
+            self.index += 1;
+            if depth == 0 {
+                return;
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for PgnMoves<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let bytes = self.pgn.as_bytes();
+        loop {
+            while self.index < bytes.len() && bytes[self.index].is_ascii_whitespace() {
+                self.index += 1;
+            }
+            if self.index >= bytes.len() {
+                return None;
+            }
+
+            match bytes[self.index] {
+                b'[' => self.skip_past(b']'),
+                b'{' => self.skip_past(b'}'),
+                b';' => self.skip_past(b'\n'),
+                b'(' => self.skip_variation(),
+                _ => {
+                    let start = self.index;
+                    while self.index < bytes.len()
+                        && !bytes[self.index].is_ascii_whitespace()
+                        && !b"[{;()".contains(&bytes[self.index])
+                    {
+                        self.index += 1;
+                    }
+                    let token = &self.pgn[start..self.index];
+
+                    if matches!(token, "1-0" | "0-1" | "1/2-1/2" | "*") {
+                        return None;
+                    }
+                    if token.starts_with('$') {
+                        continue;
+                    }
+                    // castling written with zeros is no move number
+                    if token.starts_with("0-0") {
+                        return Some(token);
+                    }
+
+                    // move numbers such as `12.` or `12...`, sometimes written against the move
+                    let san = token.trim_start_matches(|c: char| c.is_ascii_digit());
+                    let san = if san.len() < token.len() {
+                        san.trim_start_matches('.')
+                    } else {
+                        san
+                    };
+                    if !san.is_empty() {
+                        return Some(san);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The value of a tag, such as `pgn_tag(pgn, "White")`.
+pub fn pgn_tag<'a>(pgn: &'a str, name: &str) -> Option<&'a str> {
+    pgn.lines().find_map(|line| {
+        let line = line.trim().strip_prefix('[')?.strip_suffix(']')?;
+        let (tag, value) = line.split_once(' ')?;
+        if tag != name {
+            return None;
+        }
+        value.trim().strip_prefix('"')?.strip_suffix('"')
+    })
+}
+
+/// Why the contract would not go along with a move of a game.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReplayError {
+    /// The SAN names no move, or more than one.
+    Notation(NotationError),
+    /// `makeMove` rejects the move.
+    Rejected(MoveError),
+    /// `makeMove` accepts the move, but it leaves the own king in check, so the opponent
+    /// wins with `callIllegalMove`.
+    KingLeftInCheck,
+}
+
+impl ReplayError {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ReplayError::Notation(error) => error.as_str(),
+            ReplayError::Rejected(error) => error.as_str(),
+            ReplayError::KingLeftInCheck => MoveError::KingLeftInCheck.as_str(),
+        }
+    }
+}
+
+/// The first move of a game the contract would not go along with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ReplayFailure<'a> {
+    /// Counting from `0`, so even plies are white's.
+    pub ply: usize,
+    pub san: &'a str,
+    pub error: ReplayError,
+}
+
+/// Replays the moves of a PGN game the way the contract plays them, without castling and
+/// en passant, and returns the final position and the number of moves.
+pub fn replay_pgn(pgn: &str) -> Result<(Position, usize), ReplayFailure<'_>> {
+    let mut position = Position::from_board(START_BOARD, WHITE);
+    let mut plies = 0;
+    for (ply, san) in pgn_moves(pgn).enumerate() {
+        let fail = |error| ReplayFailure { ply, san, error };
+
+        let mv =
+            parse_san(&position, san.as_bytes()).map_err(|e| fail(ReplayError::Notation(e)))?;
+        check_pseudo_legal(&position, mv).map_err(|e| fail(ReplayError::Rejected(e)))?;
+
+        // what `callIllegalMove` looks at
+        let next = play(&position, mv);
+        if is_in_check(&next.board, position.side_to_move) {
+            return Err(fail(ReplayError::KingLeftInCheck));
+        }
+
+        position = Position::from_board(next.board, next.side_to_move);
+        plies = ply + 1;
+    }
+    Ok((position, plies))
+}
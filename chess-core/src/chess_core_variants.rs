@@ -0,0 +1,72 @@
+use crate::{
+    chess_core_attacks::*, chess_core_board::*, chess_core_movegen::*, chess_core_moves::*,
+};
+
+/// d5, e5, d4 and e4, the squares a king wins on in King of the Hill.
+pub const HILL_SQUARES: [Square; 4] = [27, 28, 35, 36];
+
+/// The checks a side has to give to win a game of Three-Check.
+pub const CHECKS_TO_WIN: u8 = 3;
+
+/// Drops are written down as moves from `DROP_ORIGIN` plus the kind of the piece dropped.
+pub const DROP_ORIGIN: Square = 64;
+
+/// How many queens, rooks, bishops, knights and pawns a side holds, in that order.
+pub type Reserve = [u8; 5];
+
+pub fn is_king_on_the_hill(board: &Board, color: u8) -> bool {
+    let king = make_piece(color, KING);
+    HILL_SQUARES
+        .iter()
+        .any(|&square| board[square as usize] == king)
+}
+
+/// Checks that the side to move can put `piece` from its reserve on `square`, without
+/// looking at whether the own king is left in check. Kings are never dropped, and pawns
+/// never on the first or last rank.
+pub fn check_drop(position: &Position, piece: u8, square: Square) -> Result<(), MoveError> {
+    if square >= 64 {
+        return Err(MoveError::NotOnBoard);
+    }
+    if color_of(piece) != Some(position.side_to_move) {
+        return Err(MoveError::NotYourPiece);
+    }
+    if kind_of(piece) == KING {
+        return Err(MoveError::InvalidDrop);
+    }
+    if position.board[square as usize] != EMPTY {
+        return Err(MoveError::SquareNotEmpty);
+    }
+    if kind_of(piece) == PAWN && (y_of(square) == 0 || y_of(square) == 7) {
+        return Err(MoveError::InvalidPawnDrop);
+    }
+    Ok(())
+}
+
+pub fn is_legal_drop(position: &Position, piece: u8, square: Square) -> bool {
+    if check_drop(position, piece, square).is_err() {
+        return false;
+    }
+
+    let mut board = position.board;
+    board[square as usize] = piece;
+    !is_in_check(&board, position.side_to_move)
+}
+
+/// Whether the side to move can drop any piece of `reserve` without leaving its king in
+/// check.
+pub fn has_legal_drop(position: &Position, reserve: &Reserve) -> bool {
+    (QUEEN..=PAWN)
+        .filter(|&kind| reserve[kind as usize - 1] > 0)
+        .any(|kind| {
+            let piece = make_piece(position.side_to_move, kind);
+            (0..64).any(|square| is_legal_drop(position, piece, square))
+        })
+}
+
+/// Whether the side to move is in check with no move and no drop out of it.
+pub fn is_checkmate(position: &Position, reserve: &Reserve) -> bool {
+    is_in_check(&position.board, position.side_to_move)
+        && legal_moves(position).is_empty()
+        && !has_legal_drop(position, reserve)
+}
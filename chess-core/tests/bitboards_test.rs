@@ -0,0 +1,50 @@
+use on_chain_chess_core::*;
+
+const POSITIONS: [&str; 3] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+];
+
+#[test]
+fn packed_board_round_trip() {
+    for fen in POSITIONS {
+        let board = parse_fen(fen).unwrap().board;
+        let packed = pack_board(&board);
+
+        assert_eq!(unpack_board(&packed), board);
+        assert_eq!(
+            Bitboards::from_packed(&packed),
+            Bitboards::from_board(&board)
+        );
+        for square in 0..64 {
+            assert_eq!(packed_piece(&packed, square), board[square as usize]);
+        }
+    }
+
+    // the first two squares of the start position, a black rook and a black knight
+    assert_eq!(pack_board(&START_BOARD)[0], 0xb9);
+}
+
+#[test]
+fn attack_tables() {
+    let a8 = 0;
+    let h1 = 63;
+    let e4 = parse_square(b"e4").unwrap();
+
+    assert_eq!(KNIGHT_ATTACKS[a8 as usize].count_ones(), 2);
+    assert_eq!(KNIGHT_ATTACKS[e4 as usize].count_ones(), 8);
+    assert_eq!(KING_ATTACKS[h1 as usize].count_ones(), 3);
+    assert_eq!(
+        PAWN_ATTACKS[WHITE as usize][e4 as usize],
+        bit(parse_square(b"d5").unwrap()) | bit(parse_square(b"f5").unwrap())
+    );
+    assert_eq!(rook_attacks(e4, 0).count_ones(), 14);
+    assert_eq!(bishop_attacks(a8, 0).count_ones(), 7);
+
+    // the first piece on a ray is attacked, the ones behind it are not
+    let blocker = bit(parse_square(b"e6").unwrap()) | bit(parse_square(b"e7").unwrap());
+    let attacks = rook_attacks(e4, blocker);
+    assert_ne!(attacks & bit(parse_square(b"e6").unwrap()), 0);
+    assert_eq!(attacks & bit(parse_square(b"e7").unwrap()), 0);
+}
@@ -0,0 +1,37 @@
+use on_chain_chess_core::*;
+
+fn mv(uci: &str) -> Move {
+    parse_uci(uci.as_bytes()).unwrap()
+}
+
+#[test]
+fn takes_the_most_valuable_piece() {
+    // the knight can take the rook or the pawn, the queen the bishop
+    let position = parse_fen("4k3/8/3r1p2/8/b3N3/8/8/Q3K3 w - - 0 1").unwrap();
+    assert_eq!(material_gain(&position, mv("e4d6")), 5);
+    assert_eq!(material_gain(&position, mv("e4f6")), 1);
+    assert_eq!(material_gain(&position, mv("a1a4")), 3);
+    assert_eq!(material_gain(&position, mv("e4c5")), 0);
+    assert_eq!(greedy_move(&position), Some(mv("e4d6")));
+}
+
+#[test]
+fn promotes_to_a_queen() {
+    let position = parse_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    assert_eq!(greedy_move(&position), Some(mv("a7a8q")));
+}
+
+#[test]
+fn plays_the_first_move_among_equals() {
+    let position = Position::start();
+    assert_eq!(
+        greedy_move(&position),
+        Some(legal_moves(&position).as_slice()[0])
+    );
+}
+
+#[test]
+fn has_no_move_when_mated() {
+    let position = parse_fen("7k/6Q1/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    assert_eq!(greedy_move(&position), None);
+}
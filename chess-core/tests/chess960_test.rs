@@ -0,0 +1,106 @@
+use on_chain_chess_core::*;
+
+fn mv(uci: &str) -> Move {
+    parse_uci(uci.as_bytes()).unwrap()
+}
+
+#[test]
+fn start_positions() {
+    assert_eq!(chess960_board(STANDARD_CHESS960_INDEX), START_BOARD);
+    assert_eq!(
+        chess960_position(STANDARD_CHESS960_INDEX),
+        Position::start()
+    );
+    assert_eq!(
+        chess960_back_row(0),
+        [BISHOP, BISHOP, QUEEN, KNIGHT, KNIGHT, ROOK, KING, ROOK]
+    );
+    assert_eq!(chess960_castling_files(0), [5, 7]);
+
+    let mut rows = Vec::new();
+    for index in 0..CHESS960_POSITIONS {
+        let row = chess960_back_row(index);
+        let files = |kind| (0..8).filter(move |&x| row[x] == kind);
+
+        let bishops: Vec<usize> = files(BISHOP).collect();
+        assert_eq!(bishops.len(), 2);
+        assert_ne!(bishops[0] % 2, bishops[1] % 2);
+
+        let rooks: Vec<usize> = files(ROOK).collect();
+        let king = files(KING).next().unwrap();
+        assert!(rooks[0] < king && king < rooks[1]);
+        assert_eq!(files(QUEEN).count(), 1);
+        assert_eq!(files(KNIGHT).count(), 2);
+
+        rows.push(row);
+    }
+    rows.sort();
+    rows.dedup();
+    assert_eq!(rows.len(), 960);
+}
+
+#[test]
+fn castling_ends_on_the_standard_squares() {
+    let position = parse_fen("1r4kr/8/8/8/8/8/8/1R4KR w HBhb - 0 1").unwrap();
+    assert_eq!(position.castling_files, [1, 7]);
+
+    // the king already stands on g1, it moves onto the rook to castle
+    assert_eq!(castling_move(&position, true), Some(mv("g1h1")));
+    assert_eq!(check_legal(&position, mv("g1h1")), Ok(()));
+    let next = play(&position, mv("g1h1"));
+    assert_eq!(&next.board[56..], &[6, 2, 6, 6, 6, 2, 0, 6]);
+    assert_eq!(
+        next.castling,
+        CASTLE_BLACK_KING_SIDE | CASTLE_BLACK_QUEEN_SIDE
+    );
+
+    let next = play(&position, mv("g1b1"));
+    assert_eq!(&next.board[56..], &[6, 6, 0, 2, 6, 6, 6, 2]);
+
+    let moves = legal_moves(&position);
+    assert!(moves.contains(&mv("g1h1")) && moves.contains(&mv("g1b1")));
+
+    // moving a rook gives up castling on its side only, even once it is back
+    let mut next = position;
+    for uci in ["h1h2", "h8h7", "h2h1", "h7h8"] {
+        next = play(&next, mv(uci));
+    }
+    assert_eq!(
+        next.castling,
+        CASTLE_WHITE_QUEEN_SIDE | CASTLE_BLACK_QUEEN_SIDE
+    );
+    assert_eq!(check_pseudo_legal(&next, mv("g1b1")), Ok(()));
+    assert_eq!(
+        check_pseudo_legal(&next, mv("g1h1")),
+        Err(MoveError::InvalidKingMove)
+    );
+}
+
+#[test]
+fn castling_needs_free_and_safe_squares() {
+    // the knight on c1 is in the way of the king
+    let position = parse_fen("4k3/8/8/8/8/8/8/1RN3KR w HB - 0 1").unwrap();
+    assert_eq!(
+        check_pseudo_legal(&position, mv("g1b1")),
+        Err(MoveError::InvalidKingMove)
+    );
+
+    // the king would cross e1, the rook from b1 only needs d1 to be free
+    let position = parse_fen("4r1k1/8/8/8/8/8/8/1R4KR w HB - 0 1").unwrap();
+    assert_eq!(
+        check_pseudo_legal(&position, mv("g1b1")),
+        Err(MoveError::InvalidKingMove)
+    );
+    assert_eq!(check_pseudo_legal(&position, mv("g1h1")), Ok(()));
+}
+
+#[test]
+fn castling_in_san() {
+    let position = parse_fen("1r4kr/8/8/8/8/8/8/1R4KR w HBhb - 0 1").unwrap();
+    assert_eq!(parse_san(&position, b"O-O"), Ok(mv("g1h1")));
+    assert_eq!(parse_san(&position, b"O-O-O"), Ok(mv("g1b1")));
+
+    let mut out = String::new();
+    write_san(&mut out, &position, mv("g1b1")).unwrap();
+    assert_eq!(out, "O-O-O");
+}
@@ -0,0 +1,78 @@
+use on_chain_chess_core::*;
+
+fn square(name: &str) -> Square {
+    let name = name.as_bytes();
+    (name[0] - b'a') + 8 * (b'8' - name[1])
+}
+
+#[test]
+fn pieces_are_dropped_on_empty_squares() {
+    let position = Position::start();
+    let knight = make_piece(WHITE, KNIGHT);
+
+    assert_eq!(check_drop(&position, knight, square("e4")), Ok(()));
+    assert_eq!(
+        check_drop(&position, knight, square("e2")),
+        Err(MoveError::SquareNotEmpty)
+    );
+    assert_eq!(
+        check_drop(&position, knight, 64),
+        Err(MoveError::NotOnBoard)
+    );
+    assert_eq!(
+        check_drop(&position, make_piece(BLACK, KNIGHT), square("e4")),
+        Err(MoveError::NotYourPiece)
+    );
+    assert_eq!(
+        check_drop(&position, EMPTY, square("e4")),
+        Err(MoveError::NotYourPiece)
+    );
+    assert_eq!(
+        check_drop(&position, make_piece(WHITE, KING), square("e4")),
+        Err(MoveError::InvalidDrop)
+    );
+}
+
+#[test]
+fn pawns_are_not_dropped_on_the_first_or_last_rank() {
+    let mut board = START_BOARD;
+    board[square("a1") as usize] = EMPTY;
+    board[square("a8") as usize] = EMPTY;
+    let position = Position::from_board(board, BLACK);
+    let pawn = make_piece(BLACK, PAWN);
+
+    assert_eq!(
+        check_drop(&position, pawn, square("a1")),
+        Err(MoveError::InvalidPawnDrop)
+    );
+    assert_eq!(
+        check_drop(&position, pawn, square("a8")),
+        Err(MoveError::InvalidPawnDrop)
+    );
+    assert_eq!(check_drop(&position, pawn, square("a3")), Ok(()));
+    assert_eq!(
+        check_drop(&position, make_piece(BLACK, ROOK), square("a1")),
+        Ok(())
+    );
+}
+
+#[test]
+fn drops_count_as_replies_to_a_check() {
+    // the rook on a1 checks along the back rank, only a piece dropped in between helps
+    let position = parse_fen("6k1/8/8/8/8/8/5PPP/r5K1 w - - 0 1").unwrap();
+    assert!(is_checkmate(&position, &[0; 5]));
+    assert!(is_checkmate(&position, &[0, 0, 0, 0, 3]));
+    assert!(!is_checkmate(&position, &[0, 0, 0, 1, 0]));
+
+    let knight = make_piece(WHITE, KNIGHT);
+    assert!(is_legal_drop(&position, knight, square("d1")));
+    assert!(!is_legal_drop(&position, knight, square("d4")));
+
+    // a knight check cannot be blocked
+    let position = parse_fen("6k1/8/8/8/8/8/6PP/6RK w - - 0 1").unwrap();
+    let mut board = position.board;
+    board[square("f2") as usize] = make_piece(BLACK, KNIGHT);
+    let position = Position::from_board(board, WHITE);
+    assert!(is_checkmate(&position, &[1, 1, 1, 1, 1]));
+    assert!(!is_checkmate(&Position::start(), &[0; 5]));
+}
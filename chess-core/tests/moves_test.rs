@@ -0,0 +1,194 @@
+use on_chain_chess_core::*;
+
+fn square(name: &str) -> Square {
+    parse_square(name.as_bytes()).unwrap()
+}
+
+fn mv(uci: &str) -> Move {
+    Move::new(square(&uci[..2]), square(&uci[2..4]))
+}
+
+#[test]
+fn pseudo_legal_errors() {
+    let position = Position::start();
+
+    assert_eq!(check_pseudo_legal(&position, mv("e2e4")), Ok(()));
+    assert_eq!(check_pseudo_legal(&position, mv("g1f3")), Ok(()));
+    assert_eq!(
+        check_pseudo_legal(&position, Move::new(52, 64)),
+        Err(MoveError::NotOnBoard)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("e2e2")),
+        Err(MoveError::NothingMoved)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("e7e5")),
+        Err(MoveError::NotYourPiece)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("d1e1")),
+        Err(MoveError::NotYourPiece)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("e2e5")),
+        Err(MoveError::InvalidPawnMove)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("g1g3")),
+        Err(MoveError::InvalidKnightMove)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("f1c4")),
+        Err(MoveError::InvalidBishopMove)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("a1a3")),
+        Err(MoveError::InvalidRookMove)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("d1d3")),
+        Err(MoveError::InvalidQueenMove)
+    );
+    assert_eq!(
+        check_pseudo_legal(&position, mv("e1e3")),
+        Err(MoveError::InvalidKingMove)
+    );
+}
+
+#[test]
+fn promotion_must_name_a_piece() {
+    let position = parse_fen("8/4P3/8/8/8/8/8/k6K w - - 0 1").unwrap();
+
+    assert_eq!(
+        check_pseudo_legal(&position, mv("e7e8")),
+        Err(MoveError::InvalidPromotion)
+    );
+    assert_eq!(
+        check_pseudo_legal(
+            &position,
+            Move::with_promotion(square("e7"), square("e8"), KING)
+        ),
+        Err(MoveError::InvalidPromotion)
+    );
+    let promotion = Move::with_promotion(square("e7"), square("e8"), KNIGHT);
+    assert_eq!(check_pseudo_legal(&position, promotion), Ok(()));
+    assert_eq!(
+        play(&position, promotion).board[square("e8") as usize],
+        KNIGHT
+    );
+    assert_eq!(
+        check_pseudo_legal(
+            &position,
+            Move::with_promotion(square("h1"), square("h2"), QUEEN)
+        ),
+        Err(MoveError::InvalidPromotion)
+    );
+}
+
+#[test]
+fn promoted_pieces_keep_the_pawn_color() {
+    let position = parse_fen("k7/8/8/8/8/8/4p3/7K b - - 0 1").unwrap();
+
+    let promotion = Move::with_promotion(square("e2"), square("e1"), QUEEN);
+    assert_eq!(
+        play(&position, promotion).board[square("e1") as usize],
+        make_piece(BLACK, QUEEN)
+    );
+}
+
+#[test]
+fn castling_and_en_passant() {
+    let position = parse_fen("r3k2r/8/8/3pP3/8/8/8/R3K2R w KQkq d6 0 1").unwrap();
+
+    let castled = play(&position, mv("e1g1"));
+    assert_eq!(
+        castled.board[square("g1") as usize],
+        make_piece(WHITE, KING)
+    );
+    assert_eq!(
+        castled.board[square("f1") as usize],
+        make_piece(WHITE, ROOK)
+    );
+    assert_eq!(
+        castled.castling,
+        CASTLE_BLACK_KING_SIDE | CASTLE_BLACK_QUEEN_SIDE
+    );
+
+    let captured = play(&position, mv("e5d6"));
+    assert_eq!(captured.board[square("d5") as usize], EMPTY);
+    assert_eq!(
+        captured.board[square("d6") as usize],
+        make_piece(WHITE, PAWN)
+    );
+
+    // the contract plays without castling and en passant rights
+    let contract_position = Position::from_board(position.board, WHITE);
+    assert_eq!(
+        check_pseudo_legal(&contract_position, mv("e1g1")),
+        Err(MoveError::InvalidKingMove)
+    );
+    assert_eq!(
+        check_pseudo_legal(&contract_position, mv("e5d6")),
+        Err(MoveError::InvalidPawnMove)
+    );
+}
+
+#[test]
+fn check_detection() {
+    let position = parse_fen("4k3/8/8/7b/8/8/8/4K3 w - - 0 1").unwrap();
+
+    assert!(!is_in_check(&position.board, WHITE));
+    assert!(is_square_attacked(&position.board, square("e2"), BLACK));
+    assert_eq!(check_pseudo_legal(&position, mv("e1e2")), Ok(()));
+    assert_eq!(
+        check_legal(&position, mv("e1e2")),
+        Err(MoveError::KingLeftInCheck)
+    );
+    assert_eq!(legal_moves(&position).len(), 3);
+
+    let checked = parse_fen("4k3/8/8/8/8/3n4/8/4K3 w - - 0 1").unwrap();
+    assert!(is_in_check(&checked.board, WHITE));
+    assert!(!is_in_check(&checked.board, BLACK));
+}
+
+#[test]
+fn invalid_fen() {
+    assert_eq!(parse_fen(""), Err(FenError::InvalidBoard));
+    assert_eq!(parse_fen("8/8/8 w - -"), Err(FenError::InvalidBoard));
+    assert_eq!(
+        parse_fen("9/8/8/8/8/8/8/8 w - -"),
+        Err(FenError::InvalidBoard)
+    );
+    assert_eq!(
+        parse_fen("8/8/8/8/8/8/8/8 x - -"),
+        Err(FenError::InvalidSideToMove)
+    );
+    assert_eq!(
+        parse_fen("8/8/8/8/8/8/8/8 w X -"),
+        Err(FenError::InvalidCastling)
+    );
+    assert_eq!(
+        parse_fen("8/8/8/8/8/8/8/8 w - z9"),
+        Err(FenError::InvalidEnPassant)
+    );
+}
+
+#[test]
+fn blocked_paths() {
+    let position = Position::start();
+
+    assert!(is_path_blocked(&position.board, mv("f1c4")));
+    assert!(is_path_blocked(&position.board, mv("a1a3")));
+    assert!(is_path_blocked(&position.board, mv("d1d4")));
+    // knights jump and the kings only take one step
+    assert!(!is_path_blocked(&position.board, mv("g1f3")));
+    assert!(!is_path_blocked(&position.board, mv("e1e3")));
+    // along no line at all
+    assert!(!is_path_blocked(&position.board, mv("f1e3")));
+    assert!(!is_path_blocked(&position.board, mv("e2e4")));
+
+    let position = parse_fen("4k3/8/8/8/8/4n3/4P3/4K3 w - - 0 1").unwrap();
+    assert!(is_path_blocked(&position.board, mv("e2e4")));
+    assert!(!is_path_blocked(&position.board, mv("e2e3")));
+}
@@ -0,0 +1,84 @@
+use on_chain_chess_core::*;
+
+fn square(name: &str) -> Square {
+    parse_square(name.as_bytes()).unwrap()
+}
+
+fn mv(from: &str, to: &str) -> Move {
+    Move::new(square(from), square(to))
+}
+
+#[test]
+fn uci() {
+    assert_eq!(parse_uci(b"e2e4"), Ok(mv("e2", "e4")));
+    assert_eq!(
+        parse_uci(b"e7e8q"),
+        Ok(Move::with_promotion(square("e7"), square("e8"), QUEEN))
+    );
+    assert_eq!(
+        parse_uci(b"a2a1N"),
+        Ok(Move::with_promotion(square("a2"), square("a1"), KNIGHT))
+    );
+    assert_eq!(parse_uci(b"e2e"), Err(NotationError::InvalidNotation));
+    assert_eq!(parse_uci(b"e2e4k"), Err(NotationError::InvalidNotation));
+    assert_eq!(parse_uci(b"i2e4"), Err(NotationError::InvalidNotation));
+}
+
+#[test]
+fn san() {
+    let position = Position::start();
+
+    assert_eq!(parse_san(&position, b"e4"), Ok(mv("e2", "e4")));
+    assert_eq!(parse_san(&position, b"Nf3"), Ok(mv("g1", "f3")));
+    assert_eq!(parse_san(&position, b"Nc3!?"), Ok(mv("b1", "c3")));
+    assert_eq!(parse_san(&position, b"O-O"), Ok(mv("e1", "g1")));
+    assert_eq!(parse_san(&position, b"Bc4"), Err(NotationError::NoSuchMove));
+    assert_eq!(
+        parse_san(&position, b"e"),
+        Err(NotationError::InvalidNotation)
+    );
+    assert_eq!(
+        parse_san(&position, b"Nxx3"),
+        Err(NotationError::InvalidNotation)
+    );
+
+    let position = parse_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    assert_eq!(parse_san(&position, b"O-O-O"), Ok(mv("e1", "c1")));
+
+    let position = parse_fen("4k3/8/8/8/8/8/4K3/R6R w - - 0 1").unwrap();
+    assert_eq!(
+        parse_san(&position, b"Rd1"),
+        Err(NotationError::AmbiguousMove)
+    );
+    assert_eq!(parse_san(&position, b"Rad1"), Ok(mv("a1", "d1")));
+    assert_eq!(parse_san(&position, b"R1h2"), Ok(mv("h1", "h2")));
+}
+
+#[test]
+fn san_promotion() {
+    let position = parse_fen("r3k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(
+        parse_san(&position, b"bxa8=N+"),
+        Ok(Move::with_promotion(square("b7"), square("a8"), KNIGHT))
+    );
+    assert_eq!(
+        parse_san(&position, b"bxa8Q"),
+        Ok(Move::with_promotion(square("b7"), square("a8"), QUEEN))
+    );
+    // without a letter the pawn becomes a queen, as in the contract
+    assert_eq!(
+        parse_san(&position, b"b8"),
+        Ok(Move::with_promotion(square("b7"), square("b8"), QUEEN))
+    );
+}
+
+#[test]
+fn san_prefers_legal_moves() {
+    // the knight on c3 is pinned, so only the one on g1 can go to e2
+    let position = parse_fen("4k3/4r3/8/8/1b6/2N5/8/4K1N1 w - - 0 1").unwrap();
+    assert_eq!(parse_san(&position, b"Ne2"), Ok(mv("g1", "e2")));
+
+    // moving the pinned knight is still found when it is the only one
+    let position = parse_fen("4k3/8/8/8/1b6/2N5/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(parse_san(&position, b"Ne4"), Ok(mv("c3", "e4")));
+}
@@ -0,0 +1,86 @@
+use on_chain_chess_core::*;
+
+// https://www.chessprogramming.org/Perft_Results
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+const POSITION_3: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+const POSITION_4: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+const POSITION_4_MIRRORED: &str =
+    "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1";
+const POSITION_5: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+const POSITION_6: &str = "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+
+// https://www.chessprogramming.org/Chess960_Perft_Results
+const CHESS960_1: &str = "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9";
+const CHESS960_2: &str = "2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9";
+
+fn check_perft(fen: &str, expected: &[u64]) {
+    let position = parse_fen(fen).unwrap();
+    for (depth, &nodes) in expected.iter().enumerate() {
+        assert_eq!(
+            perft(&position, depth as u32 + 1),
+            nodes,
+            "{fen} at depth {}",
+            depth + 1
+        );
+    }
+}
+
+#[test]
+fn perft_start_position() {
+    check_perft(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        &[20, 400, 8_902, 197_281],
+    );
+    assert_eq!(
+        Position::start(),
+        parse_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap()
+    );
+}
+
+#[test]
+fn perft_kiwipete() {
+    check_perft(KIWIPETE, &[48, 2_039, 97_862]);
+}
+
+#[test]
+fn perft_position_3() {
+    check_perft(POSITION_3, &[14, 191, 2_812, 43_238]);
+}
+
+#[test]
+fn perft_position_4() {
+    check_perft(POSITION_4, &[6, 264, 9_467]);
+    check_perft(POSITION_4_MIRRORED, &[6, 264, 9_467]);
+}
+
+#[test]
+fn perft_position_5() {
+    check_perft(POSITION_5, &[44, 1_486, 62_379]);
+}
+
+#[test]
+fn perft_position_6() {
+    check_perft(POSITION_6, &[46, 2_079, 89_890]);
+}
+
+#[test]
+fn perft_chess960() {
+    check_perft(CHESS960_1, &[21, 528, 12_189]);
+    check_perft(CHESS960_2, &[21, 807, 18_002]);
+}
+
+#[test]
+#[ignore = "slow in debug builds, run with --release"]
+fn perft_deep() {
+    check_perft(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        &[20, 400, 8_902, 197_281, 4_865_609],
+    );
+    check_perft(KIWIPETE, &[48, 2_039, 97_862, 4_085_603]);
+    check_perft(POSITION_3, &[14, 191, 2_812, 43_238, 674_624, 11_030_083]);
+    check_perft(POSITION_4, &[6, 264, 9_467, 422_333]);
+    check_perft(POSITION_5, &[44, 1_486, 62_379, 2_103_487]);
+    check_perft(POSITION_6, &[46, 2_079, 89_890, 3_894_594]);
+    check_perft(CHESS960_1, &[21, 528, 12_189, 326_672, 8_146_062]);
+    check_perft(CHESS960_2, &[21, 807, 18_002, 667_366, 16_253_601]);
+}
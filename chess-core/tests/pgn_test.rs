@@ -0,0 +1,223 @@
+use on_chain_chess_core::*;
+
+fn mv(uci: &str) -> Move {
+    parse_uci(uci.as_bytes()).unwrap()
+}
+
+fn san(fen: &str, uci: &str) -> String {
+    let mut out = String::new();
+    write_san(&mut out, &parse_fen(fen).unwrap(), mv(uci)).unwrap();
+    out
+}
+
+fn pgn(moves: &[&str], result: &str) -> Result<String, PgnError> {
+    let tags = PgnTags {
+        event: "On-chain chess game 1",
+        site: "MultiversX",
+        date: "????.??.??",
+        round: "-",
+        white: "erd1white",
+        black: "erd1black",
+        result,
+        variant: None,
+    };
+    let moves: Vec<Move> = moves.iter().map(|uci| mv(uci)).collect();
+
+    let mut out = String::new();
+    write_pgn(
+        &mut out,
+        &tags,
+        &Position::from_board(START_BOARD, WHITE),
+        &moves,
+    )?;
+    Ok(out)
+}
+
+#[test]
+fn san_moves() {
+    let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    assert_eq!(san(start, "e2e4"), "e4");
+    assert_eq!(san(start, "g1f3"), "Nf3");
+    assert_eq!(san("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1", "e4d5"), "exd5");
+    assert_eq!(san("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8q"), "b8=Q+");
+    assert_eq!(san("r3k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7a8n"), "bxa8=N");
+    assert_eq!(san("4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1", "e1g1"), "O-O");
+    assert_eq!(san("4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1", "e1c1"), "O-O-O");
+    assert_eq!(san("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "a1a8"), "Ra8#");
+}
+
+#[test]
+fn san_disambiguation() {
+    // by file, by rank, and by both, the last one uncovering a check from e1
+    assert_eq!(san("4k3/8/8/8/8/8/4K3/R6R w - - 0 1", "a1d1"), "Rad1");
+    assert_eq!(san("4k3/8/R7/8/8/8/8/R3K3 w - - 0 1", "a1a3"), "R1a3");
+    assert_eq!(san("4k3/8/8/8/8/2Q1Q3/8/4Q1K1 w - - 0 1", "e3d2"), "Qe3d2+");
+    // a pinned knight does not count
+    assert_eq!(san("4k3/4r3/8/8/1b6/2N5/8/4K1N1 w - - 0 1", "g1e2"), "Ne2");
+}
+
+#[test]
+fn whole_game() {
+    assert_eq!(
+        pgn(
+            &["e2e4", "e7e5", "d1h5", "b8c6", "f1c4", "g8f6", "h5f7"],
+            "1-0"
+        )
+        .unwrap(),
+        "[Event \"On-chain chess game 1\"]\n\
+         [Site \"MultiversX\"]\n\
+         [Date \"????.??.??\"]\n\
+         [Round \"-\"]\n\
+         [White \"erd1white\"]\n\
+         [Black \"erd1black\"]\n\
+         [Result \"1-0\"]\n\
+         \n\
+         1. e4 e5 2. Qh5 Nc6 3. Bc4 Nf6 4. Qxf7# 1-0\n"
+    );
+}
+
+#[test]
+fn long_games_are_wrapped() {
+    let mut moves = Vec::new();
+    for _ in 0..10 {
+        moves.extend(["g1f3", "g8f6", "f3g1", "f6g8"]);
+    }
+
+    let out = pgn(&moves, "1/2-1/2").unwrap();
+    let move_text: Vec<&str> = out.split("\n\n").nth(1).unwrap().lines().collect();
+    assert!(move_text.len() > 1);
+    assert!(move_text.iter().all(|line| line.len() < 80));
+    assert!(move_text.last().unwrap().ends_with("20. Ng1 Ng8 1/2-1/2"));
+}
+
+#[test]
+fn illegal_and_invalid_moves() {
+    // black leaves the king in check, white called it out
+    let out = pgn(&["e2e4", "f7f6", "d1h5", "a7a6"], "1-0").unwrap();
+    assert!(out.ends_with("1. e4 f6 2. Qh5+ {a7a6 left the king in check} 1-0\n"));
+
+    assert_eq!(pgn(&["e2e4", "e2e4"], "*"), Err(PgnError::InvalidMove(1)));
+}
+
+#[test]
+fn fen_round_trips() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/8/8/3pP3/8/8/8/R3K2R w Kq d6 0 1",
+        "1r4kr/8/8/8/8/8/8/1R4KR b Hb - 0 1",
+        "4k3/8/8/8/8/8/8/4K3 w - - 0 1",
+    ] {
+        let mut out = String::new();
+        write_fen(&mut out, &parse_fen(fen).unwrap()).unwrap();
+        assert_eq!(out, fen);
+    }
+}
+
+#[test]
+fn chess960_games_start_from_their_fen() {
+    let tags = PgnTags {
+        event: "On-chain chess game 1",
+        site: "MultiversX",
+        date: "????.??.??",
+        round: "-",
+        white: "erd1white",
+        black: "erd1black",
+        result: "*",
+        variant: Some("Chess960"),
+    };
+    let mut out = String::new();
+    write_pgn(
+        &mut out,
+        &tags,
+        &chess960_position(0),
+        &[mv("e2e4"), mv("e7e5")],
+    )
+    .unwrap();
+
+    assert!(out.ends_with(
+        "[Result \"*\"]\n\
+         [Variant \"Chess960\"]\n\
+         [SetUp \"1\"]\n\
+         [FEN \"bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1\"]\n\
+         \n\
+         1. e4 e5 *\n"
+    ));
+}
+
+#[test]
+fn tag_values_are_escaped() {
+    let tags = PgnTags {
+        event: "a \"quoted\" \\ name",
+        site: "?",
+        date: "????.??.??",
+        round: "-",
+        white: "?",
+        black: "?",
+        result: "*",
+        variant: None,
+    };
+    let mut out = String::new();
+    write_pgn(
+        &mut out,
+        &tags,
+        &Position::from_board(START_BOARD, WHITE),
+        &[],
+    )
+    .unwrap();
+    assert!(out.starts_with("[Event \"a \\\"quoted\\\" \\\\ name\"]\n"));
+    assert!(out.ends_with("\n\n*\n"));
+}
+
+#[test]
+fn reading_move_text() {
+    let pgn = "[Event \"Casual \\\"game\\\"\"]\n\
+               [White \"alice\"]\n\
+               \n\
+               1. e4 {best by test} e5 2.Nf3 $1 (2. f4 exf4 (2... d5) 3. Nf3) Nc6 ; old line\n\
+               3... a6?! 4. O-O 0-0 1-0 5. d4";
+
+    let moves: Vec<&str> = pgn_moves(pgn).collect();
+    assert_eq!(moves, vec!["e4", "e5", "Nf3", "Nc6", "a6?!", "O-O", "0-0"]);
+    assert_eq!(pgn_tag(pgn, "White"), Some("alice"));
+    assert_eq!(pgn_tag(pgn, "Black"), None);
+}
+
+#[test]
+fn replaying_an_exported_game() {
+    let moves = ["e2e4", "e7e5", "d1h5", "b8c6", "f1c4", "g8f6", "h5f7"];
+    let out = pgn(&moves, "1-0").unwrap();
+
+    let (position, plies) = replay_pgn(&out).unwrap();
+    assert_eq!(plies, 7);
+    assert_eq!(position.side_to_move, BLACK);
+    assert!(is_in_check(&position.board, BLACK));
+    assert!(legal_moves(&position).is_empty());
+}
+
+#[test]
+fn replaying_reports_the_first_rejected_move() {
+    // the contract knows no castling
+    assert_eq!(
+        replay_pgn("1. e4 e5 2. Nf3 Nc6 3. Bc4 Bc5 4. O-O Nf6 *"),
+        Err(ReplayFailure {
+            ply: 6,
+            san: "O-O",
+            error: ReplayError::Rejected(MoveError::InvalidKingMove),
+        })
+    );
+
+    // nor en passant
+    let failure = replay_pgn("1. e4 a6 2. e5 d5 3. exd6 *").unwrap_err();
+    assert_eq!(failure.ply, 4);
+    assert_eq!(failure.error.as_str(), "No piece can make this move");
+
+    let failure = replay_pgn("1. e4 f6 2. Qh5+ a6 *").unwrap_err();
+    assert_eq!((failure.ply, failure.san), (3, "a6"));
+    assert_eq!(failure.error, ReplayError::KingLeftInCheck);
+
+    let failure = replay_pgn("1. e4 e5 2. Ke3 *").unwrap_err();
+    assert_eq!(
+        failure.error,
+        ReplayError::Notation(NotationError::NoSuchMove)
+    );
+}
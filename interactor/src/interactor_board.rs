@@ -0,0 +1,62 @@
+const PIECE_SYMBOLS: [char; 13] = [
+    'K', 'Q', 'R', 'B', 'N', 'P', '.', 'k', 'q', 'r', 'b', 'n', 'p',
+];
+
+/// Turns a square such as `e2` into the contract's index, where `0` is a8 and `63` is h1.
+pub fn parse_square(square: &str) -> Option<u8> {
+    let mut chars = square.chars();
+    let file = chars.next()?;
+    let rank = chars.next()?;
+    if chars.next().is_some() || !('a'..='h').contains(&file) || !('1'..='8').contains(&rank) {
+        return None;
+    }
+
+    let x = file as u8 - b'a';
+    let y = b'8' - rank as u8;
+    Some(x + y * 8)
+}
+
+/// Parses moves written as `e2e4`, `e2-e4` or `e2 e4` into origin and destination squares.
+pub fn parse_move(notation: &str) -> Option<(u8, u8)> {
+    let squares: String = notation
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_lowercase();
+    if squares.len() != 4 {
+        return None;
+    }
+
+    Some((parse_square(&squares[..2])?, parse_square(&squares[2..])?))
+}
+
+/// Parses drops written as `N@e4` into the kind of piece and the square it goes on.
+pub fn parse_drop(notation: &str) -> Option<(u8, u8)> {
+    let (piece, square) = notation.trim().split_once('@')?;
+    let mut chars = piece.chars();
+    let kind = match (chars.next()?.to_ascii_uppercase(), chars.next()) {
+        ('Q', None) => 1,
+        ('R', None) => 2,
+        ('B', None) => 3,
+        ('N', None) => 4,
+        ('P', None) => 5,
+        _ => return None,
+    };
+
+    Some((kind, parse_square(&square.to_lowercase())?))
+}
+
+pub fn board_to_ascii(board: &[u8; 64]) -> String {
+    let mut ascii = String::new();
+    for y in 0..8 {
+        ascii.push_str(&format!("{} ", 8 - y));
+        for x in 0..8 {
+            let piece = board[x + y * 8] as usize;
+            ascii.push(' ');
+            ascii.push(*PIECE_SYMBOLS.get(piece).unwrap_or(&'?'));
+        }
+        ascii.push('\n');
+    }
+    ascii.push_str("   a b c d e f g h\n");
+    ascii
+}
@@ -0,0 +1,182 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::interactor_config::CONFIG_FILE;
+
+/// Deploy, play and inspect on-chain chess games
+#[derive(Parser)]
+#[command(version, about)]
+pub struct InteractCli {
+    /// Path to the TOML config file
+    #[arg(long, default_value = CONFIG_FILE)]
+    pub config: String,
+
+    /// Test wallet name or PEM file to sign with, instead of the one in the config
+    #[arg(long)]
+    pub wallet: Option<String>,
+
+    #[command(subcommand)]
+    pub command: InteractCliCommand,
+}
+
+#[derive(Subcommand)]
+pub enum InteractCliCommand {
+    /// Deploy the contract and remember its address
+    Deploy,
+    /// Set the wager token and amount for all games
+    SetWager(SetWagerArgs),
+    /// Issue the collection game results are minted in, paying the issue cost
+    IssueResultCollection(IssueResultCollectionArgs),
+    /// Pause the contract
+    Pause,
+    /// Unpause the contract
+    Unpause,
+    /// Pay the wager and join a game
+    Join(JoinArgs),
+    /// Start a game against the contract's bot, which answers every move right away
+    Practice(PracticeArgs),
+    /// Make a move, for example `e2e4`
+    Move(MoveArgs),
+    /// Drop a piece from your reserve, for example `N@e4`
+    Drop(DropArgs),
+    /// Commit to the first move of a blind opening game without showing it
+    Commit(BlindMoveArgs),
+    /// Reveal the first move committed to, with the same salt
+    Reveal(BlindMoveArgs),
+    /// Back an outcome of someone else's game during its first ten moves
+    Bet(BetArgs),
+    /// Collect the winnings, or the refund, of your side bets on a finished game
+    ClaimBet(GameArgs),
+    /// Opt in or out of getting a token with the result of every game you finish
+    ResultNfts(ResultNftsArgs),
+    /// Offer or accept a draw
+    Draw(GameArgs),
+    /// Resign a game
+    Resign(GameArgs),
+    /// Print the board of a game
+    Board(GameArgs),
+    /// Print a game as PGN, to open it in other chess tools
+    Pgn(GameArgs),
+    /// Replay a PGN file with the contract's rules, offline, and report the first move
+    /// the contract would not accept
+    VerifyPgn(VerifyPgnArgs),
+}
+
+#[derive(Args)]
+pub struct SetWagerArgs {
+    pub token_id: String,
+    pub amount: u128,
+}
+
+#[derive(Args)]
+pub struct IssueResultCollectionArgs {
+    /// Display name of the collection, such as `ChessResults`
+    pub name: String,
+    /// Ticker of the collection, such as `CHESSRES`
+    pub ticker: String,
+}
+
+#[derive(Args)]
+pub struct JoinArgs {
+    /// Only play against someone who asked for the same variant
+    #[arg(long, value_enum, default_value_t = Variant::Standard)]
+    pub variant: Variant,
+}
+
+#[derive(Args)]
+pub struct PracticeArgs {
+    /// Wager matched by the house pool, nothing is paid when left out
+    #[arg(long, default_value_t = 0)]
+    pub stake: u128,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Variant {
+    Standard,
+    Chess960,
+    KingOfTheHill,
+    ThreeCheck,
+    BlindOpening,
+    Bughouse,
+    Crazyhouse,
+}
+
+impl Variant {
+    /// The number the contract knows the variant by.
+    pub fn id(self) -> u8 {
+        match self {
+            Variant::Standard => 0,
+            Variant::Chess960 => 1,
+            Variant::KingOfTheHill => 2,
+            Variant::ThreeCheck => 3,
+            Variant::BlindOpening => 4,
+            Variant::Bughouse => 5,
+            Variant::Crazyhouse => 6,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct GameArgs {
+    pub game_id: usize,
+}
+
+#[derive(Args)]
+pub struct MoveArgs {
+    pub game_id: usize,
+    /// Origin and destination squares, as in `e2e4` or `g1-f3`
+    pub notation: String,
+}
+
+#[derive(Args)]
+pub struct DropArgs {
+    pub game_id: usize,
+    /// The piece and the square it goes on, as in `N@e4` or `p@d3`
+    pub notation: String,
+}
+
+#[derive(Args)]
+pub struct BetArgs {
+    pub game_id: usize,
+    #[arg(value_enum)]
+    pub outcome: Outcome,
+    /// Stake in the wager token
+    pub amount: u128,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Outcome {
+    White,
+    Black,
+    Draw,
+}
+
+impl Outcome {
+    /// The game state the outcome ends the game with.
+    pub fn id(self) -> u8 {
+        match self {
+            Outcome::White => 1,
+            Outcome::Black => 2,
+            Outcome::Draw => 3,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct ResultNftsArgs {
+    #[arg(action = clap::ArgAction::Set)]
+    pub wants: bool,
+}
+
+#[derive(Args)]
+pub struct BlindMoveArgs {
+    pub game_id: usize,
+    /// Origin and destination squares, as in `e2e4` or `g1-f3`
+    pub notation: String,
+    /// Any secret text, keeps the opponent from guessing the move from its hash
+    pub salt: String,
+}
+
+#[derive(Args)]
+pub struct VerifyPgnArgs {
+    pub path: String,
+}
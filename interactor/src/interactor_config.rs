@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+pub const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainType {
+    Real,
+    Simulator,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub chain_type: ChainType,
+    pub gateway_uri: String,
+    pub wallet: String,
+    pub contract_code: String,
+}
+
+impl Config {
+    pub fn load_config<P: AsRef<Path>>(path: P) -> Self {
+        let content = fs::read_to_string(path.as_ref()).unwrap_or_else(|err| {
+            panic!(
+                "could not read config file {}: {err}",
+                path.as_ref().display()
+            )
+        });
+        toml::from_str(&content).expect("invalid config file")
+    }
+
+    pub fn use_chain_simulator(&self) -> bool {
+        self.chain_type == ChainType::Simulator
+    }
+}
@@ -0,0 +1,658 @@
+mod interactor_board;
+mod interactor_cli;
+mod interactor_config;
+mod interactor_pgn;
+mod proxy;
+
+use clap::Parser;
+use interactor_board::{board_to_ascii, parse_drop, parse_move};
+use interactor_cli::{InteractCli, InteractCliCommand, Outcome, Variant};
+use interactor_config::Config;
+use interactor_pgn::{game_to_pgn, verify_pgn};
+use multiversx_sc_snippets::{
+    imports::*, multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::keccak256,
+    sdk::wallet::Wallet,
+};
+use on_chain_chess_core::{chess960_position, Position, START_BOARD, WHITE};
+use serde::{Deserialize, Serialize};
+use std::{path::Path, time::Duration};
+
+const STATE_FILE: &str = "state.toml";
+const SIMULATOR_BLOCK_INTERVAL: Duration = Duration::from_millis(500);
+// what the system SC charges for issuing a token, 0.05 EGLD
+const ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let cli = InteractCli::parse();
+
+    // runs offline, so it needs neither the config nor a wallet
+    if let InteractCliCommand::VerifyPgn(args) = &cli.command {
+        let pgn = std::fs::read_to_string(&args.path)
+            .unwrap_or_else(|error| panic!("could not read {}: {error}", args.path));
+        println!("{}", verify_pgn(&pgn));
+        return;
+    }
+
+    let config = Config::load_config(&cli.config);
+    let wallet = cli.wallet.clone().unwrap_or(config.wallet.clone());
+    let mut interact = ContractInteract::new(config, &wallet).await;
+
+    match cli.command {
+        InteractCliCommand::Deploy => interact.deploy().await,
+        InteractCliCommand::SetWager(args) => interact.set_wager(&args.token_id, args.amount).await,
+        InteractCliCommand::IssueResultCollection(args) => {
+            interact
+                .issue_result_collection(&args.name, &args.ticker)
+                .await
+        }
+        InteractCliCommand::Pause => interact.pause().await,
+        InteractCliCommand::Unpause => interact.unpause().await,
+        InteractCliCommand::Join(args) => interact.join_game(args.variant).await,
+        InteractCliCommand::Practice(args) => interact.start_practice_game(args.stake).await,
+        InteractCliCommand::Move(args) => interact.make_move(args.game_id, &args.notation).await,
+        InteractCliCommand::Drop(args) => interact.drop_piece(args.game_id, &args.notation).await,
+        InteractCliCommand::Commit(args) => {
+            interact
+                .commit_move(args.game_id, &args.notation, &args.salt)
+                .await
+        }
+        InteractCliCommand::Reveal(args) => {
+            interact
+                .reveal_move(args.game_id, &args.notation, &args.salt)
+                .await
+        }
+        InteractCliCommand::Bet(args) => {
+            interact
+                .place_side_bet(args.game_id, args.outcome, args.amount)
+                .await
+        }
+        InteractCliCommand::ClaimBet(args) => interact.claim_side_bet(args.game_id).await,
+        InteractCliCommand::ResultNfts(args) => interact.set_wants_result_nft(args.wants).await,
+        InteractCliCommand::Draw(args) => interact.draw(args.game_id).await,
+        InteractCliCommand::Resign(args) => interact.resign(args.game_id).await,
+        InteractCliCommand::Board(args) => interact.print_board(args.game_id).await,
+        InteractCliCommand::Pgn(args) => {
+            if let Err(error) = interact.print_pgn(args.game_id).await {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        }
+        InteractCliCommand::VerifyPgn(_) => unreachable!(),
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct State {
+    contract_address: Option<Bech32Address>,
+}
+
+impl State {
+    // Deserializes state from file
+    pub fn load_state() -> Self {
+        if Path::new(STATE_FILE).exists() {
+            let content = std::fs::read_to_string(STATE_FILE).unwrap();
+            toml::from_str(&content).unwrap()
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Sets the contract address
+    pub fn set_address(&mut self, address: Bech32Address) {
+        self.contract_address = Some(address);
+    }
+
+    /// Returns the contract address
+    pub fn current_address(&self) -> &Bech32Address {
+        self.contract_address
+            .as_ref()
+            .expect("no known contract, deploy first")
+    }
+}
+
+impl Drop for State {
+    // Serializes state to file
+    fn drop(&mut self) {
+        std::fs::write(STATE_FILE, toml::to_string(self).unwrap()).unwrap();
+    }
+}
+
+struct ContractInteract {
+    interactor: Interactor,
+    wallet_address: Address,
+    contract_code: BytesValue,
+    state: State,
+}
+
+impl ContractInteract {
+    async fn new(config: Config, wallet: &str) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri).await;
+        let wallet_address = interactor.register_wallet(load_wallet(wallet));
+
+        if config.use_chain_simulator() {
+            ChainSimulator::new(&config.gateway_uri)
+                .start(&Bech32Address::from(&wallet_address))
+                .await;
+        }
+
+        let contract_code = BytesValue::interpret_from(
+            format!("mxsc:{}", config.contract_code),
+            &InterpreterContext::default(),
+        );
+
+        ContractInteract {
+            interactor,
+            wallet_address,
+            contract_code,
+            state: State::load_state(),
+        }
+    }
+
+    async fn deploy(&mut self) {
+        let new_address = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .gas(60_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .init()
+            .code(&self.contract_code)
+            .returns(ReturnsNewBech32Address)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        self.state.set_address(new_address);
+    }
+
+    async fn set_wager(&mut self, token_id: &str, amount: u128) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(10_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .set_wager(TokenIdentifier::from(token_id), BigUint::from(amount))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("wager set to {amount} {token_id}");
+    }
+
+    async fn issue_result_collection(&mut self, name: &str, ticker: &str) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(100_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .issue_result_collection(name, ticker)
+            .egld(BigUint::from(ISSUE_COST))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("issued the result collection {ticker}");
+    }
+
+    async fn pause(&mut self) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(10_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .pause()
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("contract paused");
+    }
+
+    async fn unpause(&mut self) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(10_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .unpause()
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("contract unpaused");
+    }
+
+    async fn join_game(&mut self, variant: Variant) {
+        let wager_token_id = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .wager_token_id()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        let wager_amount = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .wager_amount()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .join_game(OptionalValue::Some(variant.id()))
+            .single_esdt(&wager_token_id, 0u64, &BigUint::from(&wager_amount))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("joined with a wager of {wager_amount} {wager_token_id}");
+    }
+
+    async fn start_practice_game(&mut self, stake: u128) {
+        let wager_token_id = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .wager_token_id()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        let tx = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .start_practice_game()
+            .returns(ReturnsResult);
+
+        // the house only takes stakes in the wager token
+        let game_id = if stake == 0 {
+            tx.prepare_async().run().await
+        } else {
+            tx.single_esdt(&wager_token_id, 0u64, &BigUint::from(stake))
+                .prepare_async()
+                .run()
+                .await
+        };
+
+        println!("started practice game {game_id}, you play white");
+    }
+
+    async fn place_side_bet(&mut self, game_id: usize, outcome: Outcome, amount: u128) {
+        let wager_token_id = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .wager_token_id()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .place_side_bet(game_id, outcome.id())
+            .single_esdt(&wager_token_id, 0u64, &BigUint::from(amount))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("bet {amount} {wager_token_id} on game {game_id}");
+    }
+
+    async fn claim_side_bet(&mut self, game_id: usize) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .claim_side_bet(game_id)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("claimed the side bets on game {game_id}");
+    }
+
+    async fn set_wants_result_nft(&mut self, wants: bool) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(10_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .set_wants_result_nft(wants)
+            .prepare_async()
+            .run()
+            .await;
+
+        if wants {
+            println!("you will get a token with the result of your games");
+        } else {
+            println!("you will no longer get tokens with the results of your games");
+        }
+    }
+
+    async fn make_move(&mut self, game_id: usize, notation: &str) {
+        let (from, to) = parse_move(notation)
+            .unwrap_or_else(|| panic!("invalid move {notation}, expected something like e2e4"));
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .make_move(game_id, from, to)
+            .prepare_async()
+            .run()
+            .await;
+
+        self.print_board(game_id).await;
+    }
+
+    async fn drop_piece(&mut self, game_id: usize, notation: &str) {
+        let (kind, square) = parse_drop(notation)
+            .unwrap_or_else(|| panic!("invalid drop {notation}, expected something like N@e4"));
+
+        let game = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .get_game_snapshot(game_id)
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await
+            .game;
+
+        // the piece takes the color of the side to move, black pieces come after the white ones
+        let piece = kind + 7 * game.player_turn;
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .drop_piece(game_id, piece, square)
+            .prepare_async()
+            .run()
+            .await;
+
+        self.print_board(game_id).await;
+    }
+
+    async fn commit_move(&mut self, game_id: usize, notation: &str, salt: &str) {
+        let (from, to) = parse_move(notation)
+            .unwrap_or_else(|| panic!("invalid move {notation}, expected something like e2e4"));
+
+        let mut preimage = vec![from, to];
+        preimage.extend_from_slice(salt.as_bytes());
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .commit_move(
+                game_id,
+                ManagedByteArray::new_from_bytes(&keccak256(&preimage)),
+            )
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("committed to a move in game {game_id}, reveal it with the same salt");
+    }
+
+    async fn reveal_move(&mut self, game_id: usize, notation: &str, salt: &str) {
+        let (from, to) = parse_move(notation)
+            .unwrap_or_else(|| panic!("invalid move {notation}, expected something like e2e4"));
+
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(40_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .reveal_move(game_id, from, to, salt)
+            .prepare_async()
+            .run()
+            .await;
+
+        self.print_board(game_id).await;
+    }
+
+    async fn draw(&mut self, game_id: usize) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .draw(game_id)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("draw offered or accepted in game {game_id}");
+    }
+
+    async fn resign(&mut self, game_id: usize) {
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::OnChainChessScProxy)
+            .resign(game_id)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("resigned game {game_id}");
+    }
+
+    async fn print_board(&mut self, game_id: usize) {
+        let board = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .get_board(game_id)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        let move_count = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .move_count(game_id)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        let side_to_move = if move_count % 2 == 0 {
+            "white"
+        } else {
+            "black"
+        };
+
+        println!("game {game_id}, {side_to_move} to move\n");
+        print!("{}", board_to_ascii(&board));
+    }
+
+    async fn print_pgn(&mut self, game_id: usize) -> Result<(), String> {
+        let snapshot = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .get_game_snapshot(game_id)
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        let moves: Vec<_> = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::OnChainChessScProxy)
+            .move_history(game_id)
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await
+            .into_iter()
+            .map(|mv| (mv.from, mv.to, mv.promotion))
+            .collect();
+
+        if [Variant::Bughouse.id(), Variant::Crazyhouse.id()].contains(&snapshot.game.variant) {
+            return Err(format!(
+                "game {game_id} has drops, which PGN cannot describe"
+            ));
+        }
+        // PGN readers count the checks from the moves, which need not match what the
+        // contract counted once a move left the own king in check
+        if snapshot.game.variant == Variant::ThreeCheck.id() {
+            return Err(format!(
+                "game {game_id} is a Three-Check game, whose check counts PGN cannot describe"
+            ));
+        }
+        if moves.len() as u64 != snapshot.move_count {
+            return Err(format!(
+                "game {game_id} started before moves were recorded, it cannot be exported"
+            ));
+        }
+
+        let (start, variant) = if snapshot.game.variant == Variant::Chess960.id() {
+            let index = self
+                .interactor
+                .query()
+                .to(self.state.current_address())
+                .typed(proxy::OnChainChessScProxy)
+                .chess960_index(game_id)
+                .returns(ReturnsResult)
+                .prepare_async()
+                .run()
+                .await
+                .ok_or_else(|| format!("the start position of game {game_id} was not recorded"))?;
+            (chess960_position(index), Some("Chess960"))
+        } else if snapshot.game.variant == Variant::KingOfTheHill.id() {
+            (
+                Position::from_board(START_BOARD, WHITE),
+                Some("King of the Hill"),
+            )
+        } else {
+            (Position::from_board(START_BOARD, WHITE), None)
+        };
+
+        let white = Bech32Address::from(snapshot.game.white_player.to_address());
+        let black = Bech32Address::from(snapshot.game.black_player.to_address());
+        let pgn = game_to_pgn(
+            game_id,
+            &white.to_bech32_string(),
+            &black.to_bech32_string(),
+            snapshot.game.state,
+            variant,
+            &start,
+            &moves,
+        )?;
+        print!("{pgn}");
+        Ok(())
+    }
+}
+
+fn load_wallet(wallet: &str) -> Wallet {
+    match wallet {
+        "alice" => test_wallets::alice(),
+        "bob" => test_wallets::bob(),
+        "carol" => test_wallets::carol(),
+        "dan" => test_wallets::dan(),
+        "eve" => test_wallets::eve(),
+        "frank" => test_wallets::frank(),
+        "grace" => test_wallets::grace(),
+        "heidi" => test_wallets::heidi(),
+        "ivan" => test_wallets::ivan(),
+        "judy" => test_wallets::judy(),
+        "mallory" => test_wallets::mallory(),
+        "mike" => test_wallets::mike(),
+        pem_file => Wallet::from_pem_file(pem_file)
+            .unwrap_or_else(|err| panic!("could not load wallet {pem_file}: {err}")),
+    }
+}
+
+/// The chain simulator only produces blocks on demand, so we keep asking for them
+/// while the interactor waits for its transactions.
+struct ChainSimulator {
+    client: reqwest::Client,
+    gateway_uri: String,
+}
+
+impl ChainSimulator {
+    fn new(gateway_uri: &str) -> Self {
+        ChainSimulator {
+            client: reqwest::Client::new(),
+            gateway_uri: gateway_uri.trim_end_matches('/').to_string(),
+        }
+    }
+
+    async fn start(self, wallet_address: &Bech32Address) {
+        self.client
+            .post(format!("{}/transaction/send-user-funds", self.gateway_uri))
+            .json(&serde_json::json!({ "receiver": wallet_address.to_bech32_string() }))
+            .send()
+            .await
+            .expect("could not fund the wallet on the chain simulator");
+
+        tokio::spawn(async move {
+            loop {
+                let _ = self
+                    .client
+                    .post(format!("{}/simulator/generate-blocks/1", self.gateway_uri))
+                    .send()
+                    .await;
+                tokio::time::sleep(SIMULATOR_BLOCK_INTERVAL).await;
+            }
+        });
+    }
+}
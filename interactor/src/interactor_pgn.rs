@@ -0,0 +1,80 @@
+use on_chain_chess_core::{replay_pgn, write_pgn, Move, PgnError, PgnTags, Position, ReplayError};
+
+/// The PGN result of a game state, aborted games count as unfinished.
+pub fn pgn_result(state: u8) -> &'static str {
+    match state {
+        1 => "1-0",
+        2 => "0-1",
+        3 => "1/2-1/2",
+        _ => "*",
+    }
+}
+
+/// Writes a game from its recorded moves, given as origin, destination and promotion,
+/// played from `start`; `variant` names the rules of games other than standard chess.
+pub fn game_to_pgn(
+    game_id: usize,
+    white: &str,
+    black: &str,
+    state: u8,
+    variant: Option<&str>,
+    start: &Position,
+    moves: &[(u8, u8, Option<u8>)],
+) -> Result<String, String> {
+    let event = format!("On-chain chess game {game_id}");
+    let tags = PgnTags {
+        event: &event,
+        site: "MultiversX",
+        date: "????.??.??",
+        round: "-",
+        white,
+        black,
+        result: pgn_result(state),
+        variant,
+    };
+    let moves: Vec<Move> = moves
+        .iter()
+        .map(|&(from, to, promotion)| Move {
+            from,
+            to,
+            promotion,
+        })
+        .collect();
+
+    let mut pgn = String::new();
+    match write_pgn(&mut pgn, &tags, start, &moves) {
+        Ok(()) => Ok(pgn),
+        Err(PgnError::InvalidMove(index)) => Err(format!(
+            "move {} of game {game_id} cannot be played",
+            index + 1
+        )),
+        Err(PgnError::Write) => Err(format!("could not write game {game_id}")),
+    }
+}
+
+/// Replays a PGN game with the contract's rules and describes the first move it would not
+/// go along with.
+pub fn verify_pgn(pgn: &str) -> String {
+    match replay_pgn(pgn) {
+        Ok((_, plies)) => format!("all {plies} moves are accepted by the contract"),
+        Err(failure) => {
+            let color = if failure.ply % 2 == 0 {
+                "white"
+            } else {
+                "black"
+            };
+            let consequence = match failure.error {
+                ReplayError::KingLeftInCheck => {
+                    "makeMove accepts it, but the opponent can win with callIllegalMove"
+                }
+                _ => "makeMove rejects it",
+            };
+            format!(
+                "move {} by {color}, {}: {}; {consequence}",
+                failure.ply / 2 + 1,
+                failure.san,
+                failure.error.as_str(),
+            )
+        }
+    }
+}
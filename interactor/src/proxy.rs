@@ -0,0 +1,1297 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct OnChainChessScProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for OnChainChessScProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = OnChainChessScProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        OnChainChessScProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct OnChainChessScProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> OnChainChessScProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> OnChainChessScProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> OnChainChessScProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn migrate_games<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start: Arg0,
+        end: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrateGames")
+            .argument(&start)
+            .argument(&end)
+            .original_result()
+    }
+
+    pub fn is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn wager_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWagerTokenId")
+            .original_result()
+    }
+
+    pub fn wager_amount(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWagerAmount")
+            .original_result()
+    }
+
+    pub fn score<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        player: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getScore")
+            .argument(&player)
+            .original_result()
+    }
+
+    pub fn is_player_waiting_opt(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIsPlayerWaitingOpt")
+            .original_result()
+    }
+
+    pub fn waiting_player<
+        Arg0: ProxyArg<u8>,
+    >(
+        self,
+        variant: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWaitingPlayer")
+            .argument(&variant)
+            .original_result()
+    }
+
+    pub fn bughouse_queue(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBughouseQueue")
+            .original_result()
+    }
+
+    pub fn migrated_games(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMigratedGames")
+            .original_result()
+    }
+
+    pub fn games(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Game<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGames")
+            .original_result()
+    }
+
+    pub fn castling_rights<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCastlingRights")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn chess960_index<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<u16>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getChess960Index")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn checks_given<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        color: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getChecksGiven")
+            .argument(&game_id)
+            .argument(&color)
+            .original_result()
+    }
+
+    pub fn move_commitment<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        color: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMoveCommitment")
+            .argument(&game_id)
+            .argument(&color)
+            .original_result()
+    }
+
+    pub fn revealed_move<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        color: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PlayedMove> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRevealedMove")
+            .argument(&game_id)
+            .argument(&color)
+            .original_result()
+    }
+
+    pub fn linked_game<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLinkedGame")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn reserve<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        color: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, [u8; 5]> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserve")
+            .argument(&game_id)
+            .argument(&color)
+            .original_result()
+    }
+
+    pub fn move_count<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMoveCount")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn draw_offer<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<DrawOffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDrawOffer")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn last_move<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<LastMove>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastMove")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn move_history<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PlayedMove>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMoveHistory")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn takeback_request<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTakebackRequest")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn max_practice_wager(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxPracticeWager")
+            .original_result()
+    }
+
+    pub fn house_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHousePool")
+            .original_result()
+    }
+
+    pub fn practice_wager<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPracticeWager")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn side_bet_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSideBetFee")
+            .original_result()
+    }
+
+    pub fn side_pool<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        outcome: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSidePool")
+            .argument(&game_id)
+            .argument(&outcome)
+            .original_result()
+    }
+
+    pub fn side_bet<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        game_id: Arg0,
+        outcome: Arg1,
+        bettor: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSideBet")
+            .argument(&game_id)
+            .argument(&outcome)
+            .argument(&bettor)
+            .original_result()
+    }
+
+    pub fn side_payout<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSidePayout")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn result_collection(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getResultCollection")
+            .original_result()
+    }
+
+    pub fn wants_result_nft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        player: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWantsResultNft")
+            .argument(&player)
+            .original_result()
+    }
+
+    pub fn tournament_organizers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTournamentOrganizers")
+            .original_result()
+    }
+
+    pub fn tournaments(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Tournament<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTournaments")
+            .original_result()
+    }
+
+    pub fn tournament_players<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tournament_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTournamentPlayers")
+            .argument(&tournament_id)
+            .original_result()
+    }
+
+    pub fn tournament_points<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        tournament_id: Arg0,
+        player: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTournamentPoints")
+            .argument(&tournament_id)
+            .argument(&player)
+            .original_result()
+    }
+
+    pub fn tournament_round_games<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        tournament_id: Arg0,
+        round: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTournamentRoundGames")
+            .argument(&tournament_id)
+            .argument(&round)
+            .original_result()
+    }
+
+    pub fn game_tournament<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGameTournament")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn bracket_winner<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        tournament_id: Arg0,
+        round: Arg1,
+        position: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBracketWinner")
+            .argument(&tournament_id)
+            .argument(&round)
+            .argument(&position)
+            .original_result()
+    }
+
+    pub fn is_armageddon<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isArmageddon")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn set_wager<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        wager_token_id: Arg0,
+        wager_amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWager")
+            .argument(&wager_token_id)
+            .argument(&wager_amount)
+            .original_result()
+    }
+
+    pub fn add_tournament_organizer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        organizer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTournamentOrganizer")
+            .argument(&organizer)
+            .original_result()
+    }
+
+    pub fn remove_tournament_organizer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        organizer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeTournamentOrganizer")
+            .argument(&organizer)
+            .original_result()
+    }
+
+    pub fn set_max_practice_wager<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxPracticeWager")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn withdraw_house_pool<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawHousePool")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_side_bet_fee<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        fee: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSideBetFee")
+            .argument(&fee)
+            .original_result()
+    }
+
+    pub fn get_board<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, [u8; 64]> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBoard")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn join_game<
+        Arg0: ProxyArg<OptionalValue<u8>>,
+    >(
+        self,
+        variant: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("joinGame")
+            .argument(&variant)
+            .original_result()
+    }
+
+    pub fn make_move<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        from: Arg1,
+        to: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("makeMove")
+            .argument(&game_id)
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn make_move_uci<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        game_id: Arg0,
+        notation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("makeMoveUci")
+            .argument(&game_id)
+            .argument(&notation)
+            .original_result()
+    }
+
+    pub fn make_move_san<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        game_id: Arg0,
+        notation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("makeMoveSan")
+            .argument(&game_id)
+            .argument(&notation)
+            .original_result()
+    }
+
+    pub fn drop_piece<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        piece: Arg1,
+        square: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("dropPiece")
+            .argument(&game_id)
+            .argument(&piece)
+            .argument(&square)
+            .original_result()
+    }
+
+    pub fn commit_move<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        game_id: Arg0,
+        hash: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("commitMove")
+            .argument(&game_id)
+            .argument(&hash)
+            .original_result()
+    }
+
+    pub fn reveal_move<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<u8>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        game_id: Arg0,
+        from: Arg1,
+        to: Arg2,
+        salt: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revealMove")
+            .argument(&game_id)
+            .argument(&from)
+            .argument(&to)
+            .argument(&salt)
+            .original_result()
+    }
+
+    pub fn request_takeback<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("requestTakeback")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn accept_takeback<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptTakeback")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn draw<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("draw")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn withdraw_draw_offer<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawDrawOffer")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn call_illegal_move<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("callIllegalMove")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn signal_inactivity<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("signalInactivity")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn resign<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resign")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn abort_game<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("abortGame")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn start_practice_game(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, usize> {
+        self.wrapped_tx
+            .raw_call("startPracticeGame")
+            .original_result()
+    }
+
+    pub fn issue_result_collection<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+        ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueResultCollection")
+            .argument(&name)
+            .argument(&ticker)
+            .original_result()
+    }
+
+    pub fn set_wants_result_nft<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        wants: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWantsResultNft")
+            .argument(&wants)
+            .original_result()
+    }
+
+    pub fn place_side_bet<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        outcome: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("placeSideBet")
+            .argument(&game_id)
+            .argument(&outcome)
+            .original_result()
+    }
+
+    pub fn claim_side_bet<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimSideBet")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn create_tournament<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<u8>,
+        Arg3: ProxyArg<u8>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, u32>>,
+    >(
+        self,
+        entry_fee: Arg0,
+        rounds: Arg1,
+        pairing_system: Arg2,
+        draw_rule: Arg3,
+        payout_table: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createTournament")
+            .argument(&entry_fee)
+            .argument(&rounds)
+            .argument(&pairing_system)
+            .argument(&draw_rule)
+            .argument(&payout_table)
+            .original_result()
+    }
+
+    pub fn register_for_tournament<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tournament_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("registerForTournament")
+            .argument(&tournament_id)
+            .original_result()
+    }
+
+    pub fn cancel_tournament<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tournament_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelTournament")
+            .argument(&tournament_id)
+            .original_result()
+    }
+
+    pub fn start_tournament<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tournament_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("startTournament")
+            .argument(&tournament_id)
+            .original_result()
+    }
+
+    pub fn next_round<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tournament_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("nextRound")
+            .argument(&tournament_id)
+            .original_result()
+    }
+
+    pub fn get_tournament_standings<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tournament_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTournamentStandings")
+            .argument(&tournament_id)
+            .original_result()
+    }
+
+    pub fn get_game_snapshot<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, GameSnapshot<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGameSnapshot")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn get_legal_moves<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        game_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, LegalMove>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLegalMoves")
+            .argument(&game_id)
+            .original_result()
+    }
+
+    pub fn get_legal_moves_from<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        game_id: Arg0,
+        square: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, LegalMove>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLegalMovesFrom")
+            .argument(&game_id)
+            .argument(&square)
+            .original_result()
+    }
+
+    pub fn validate_move<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u8>,
+        Arg3: ProxyArg<u8>,
+        Arg4: ProxyArg<OptionalValue<u8>>,
+    >(
+        self,
+        game_id: Arg0,
+        player: Arg1,
+        from: Arg2,
+        to: Arg3,
+        promotion: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MoveValidity> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("validateMove")
+            .argument(&game_id)
+            .argument(&player)
+            .argument(&from)
+            .argument(&to)
+            .argument(&promotion)
+            .original_result()
+    }
+
+    pub fn get_games_in_range<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        offset: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Game<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGamesInRange")
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_games_by_player<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        player: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Game<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGamesByPlayer")
+            .argument(&player)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_games_by_state<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        ongoing: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Game<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGamesByState")
+            .argument(&ongoing)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode)]
+pub struct Game<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub game_id: usize,
+    pub white_player: ManagedAddress<Api>,
+    pub black_player: ManagedAddress<Api>,
+    pub player_turn: u8,
+    pub last_move_epoch: u64,
+    pub state: u8,
+    pub variant: u8,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode, Clone, Copy, ManagedVecItem)]
+pub struct PlayedMove {
+    pub from: u8,
+    pub to: u8,
+    pub promotion: Option<u8>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode)]
+pub struct DrawOffer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub player: ManagedAddress<Api>,
+    pub move_number: u64,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode, Clone, Copy)]
+pub struct LastMove {
+    pub from: u8,
+    pub to: u8,
+    pub moved_piece: u8,
+    pub captured_piece: u8,
+    pub flags: u8,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode)]
+pub struct Tournament<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub tournament_id: usize,
+    pub organizer: ManagedAddress<Api>,
+    pub entry_fee: BigUint<Api>,
+    pub rounds: u32,
+    pub current_round: u32,
+    pub pairing_system: u8,
+    pub draw_rule: u8,
+    pub payout_table: ManagedVec<Api, u32>,
+    pub prize_pool: BigUint<Api>,
+    pub state: u8,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct GameSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub game: Game<Api>,
+    pub board: [u8; 64],
+    pub white_score: u64,
+    pub black_score: u64,
+    pub move_count: u64,
+    pub draw_offer: Option<DrawOffer<Api>>,
+    pub takeback_request: Option<ManagedAddress<Api>>,
+    pub last_move: Option<LastMove>,
+    pub current_epoch: u64,
+    pub move_deadline_epoch: u64,
+    pub in_check: bool,
+    pub checkmate: bool,
+    pub legal_moves: ManagedVec<Api, LegalMove>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem, PartialEq, Debug, Clone, Copy)]
+pub struct LegalMove {
+    pub from: u8,
+    pub to: u8,
+    pub promotion: Option<u8>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Debug, Clone, Copy)]
+pub enum MoveValidity {
+    Valid,
+    ContractPaused,
+    GameNotFound,
+    GameNotActive,
+    NotAPlayer,
+    NotYourTurn,
+    TimeExpired,
+    NotOnBoard,
+    NothingMoved,
+    EmptySquare,
+    WrongColor,
+    OwnPieceOnTarget,
+    InvalidPieceMove,
+    BlockedPath,
+    InvalidPromotion,
+    LeavesKingInCheck,
+    MoveNotCommitted,
+}
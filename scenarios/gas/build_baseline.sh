@@ -0,0 +1,14 @@
+#!/bin/sh
+# Builds the contract of the first commit, a byte per square and the ray walk, into
+# `baseline/on-chain-chess-sc.wasm` for board_gas_baseline.scen.json.
+set -e
+
+cd "$(dirname "$0")"
+rm -rf baseline
+mkdir -p baseline/src
+git -C ../.. archive "$(git rev-list --max-parents=0 HEAD)" | tar -x -C baseline/src
+
+# that workspace lists the interactor before it existed
+sed -i '/"interactor",/d' baseline/src/Cargo.toml
+(cd baseline/src/meta && cargo run -- build)
+cp baseline/src/output/on-chain-chess-sc.wasm baseline/
@@ -1,25 +1,127 @@
 #![no_std]
 
+use on_chain_chess_core::START_BOARD;
+use on_chain_chess_sc_storage::{DrawOffer, Game, GameId, VARIANT_STANDARD};
+
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+// raised whenever the games stored by older versions need migrating
+const STORAGE_VERSION: u32 = 1;
+
+pub mod on_chain_chess_sc_common;
 pub mod on_chain_chess_sc_interactions;
 pub mod on_chain_chess_sc_owner_interactions;
+pub mod on_chain_chess_sc_practice;
+pub mod on_chain_chess_sc_proxy;
 pub mod on_chain_chess_sc_requirements;
+pub mod on_chain_chess_sc_result_nfts;
+pub mod on_chain_chess_sc_side_bets;
 pub mod on_chain_chess_sc_storage;
+pub mod on_chain_chess_sc_tournament;
+pub mod on_chain_chess_sc_views;
 
 #[multiversx_sc::contract]
 pub trait OnChainChessSc:
     on_chain_chess_sc_storage::OnChainChessScStorage
     + on_chain_chess_sc_owner_interactions::OnChainChessScAdminInteractions
+    + on_chain_chess_sc_common::OnChainChessScCommon
     + on_chain_chess_sc_interactions::OnChainChessScInteractions
+    + on_chain_chess_sc_practice::OnChainChessScPractice
     + on_chain_chess_sc_requirements::OnChainChessScRequirements
+    + on_chain_chess_sc_result_nfts::OnChainChessScResultNfts
+    + on_chain_chess_sc_side_bets::OnChainChessScSideBets
+    + on_chain_chess_sc_tournament::OnChainChessScTournament
+    + on_chain_chess_sc_views::OnChainChessScViews
 {
     #[init]
     fn init(&self) {
         self.is_paused().set(true);
+        self.storage_version().set(STORAGE_VERSION);
     }
 
+    // games stored with an older layout are migrated in batches with `migrateGames`, the
+    // contract stays paused until then; upgrades keeping the layout leave everything as is
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        if self.storage_version().get() == STORAGE_VERSION {
+            return;
+        }
+
+        self.is_paused().set(true);
+        self.migration_end().set(self.games().len());
+        self.migrated_games().clear();
+        self.storage_version().set(STORAGE_VERSION);
+    }
+
+    // games must be migrated in order, `start` being the first one not migrated yet
+    #[only_owner]
+    #[endpoint(migrateGames)]
+    fn migrate_games(&self, start: GameId, end: GameId) {
+        require!(
+            start == self.migrated_games().get() + 1,
+            "Games must be migrated in order"
+        );
+        require!(
+            start <= end && end <= self.migration_end().get(),
+            "No such games to migrate"
+        );
+
+        for game_id in start..=end {
+            self.migrate_game(game_id);
+        }
+        self.migrated_games().set(end);
+    }
+
+    fn migrate_game(&self, game_id: GameId) {
+        // older versions stored games without a variant, those are all standard chess
+        if self.legacy_game(game_id).raw_byte_length() == 78 {
+            let (game_id, white_player, black_player, player_turn, last_move_epoch, state) =
+                self.legacy_game(game_id).get();
+            let game = Game {
+                game_id,
+                white_player,
+                black_player,
+                player_turn,
+                last_move_epoch,
+                state,
+                variant: VARIANT_STANDARD,
+            };
+            self.games().set(game_id, &game);
+        }
+
+        // older versions did not index games by player and state
+        let game = self.games().get(game_id);
+        let white_games = self.player_games(&game.white_player);
+        if white_games.is_empty() || white_games.get(white_games.len()) < game_id {
+            self.index_game(&game);
+        }
+
+        // older versions stored a draw offer as the address of the player who made it
+        if self.draw_offer(game_id).raw_byte_length() == 33 {
+            if let Some(player) = self.legacy_draw_offer(game_id).get() {
+                self.draw_offer(game_id).set(Some(DrawOffer {
+                    player,
+                    move_number: self.move_count(game_id).get(),
+                    timestamp: self.blockchain().get_block_timestamp(),
+                }));
+            }
+        }
+
+        // repack the boards stored by older versions
+        if self.board(game_id).raw_byte_length() == 64 {
+            let board = self.legacy_board(game_id).get();
+            self.set_board(game_id, &board);
+        }
+
+        // older versions did not count moves, a game that left the start position is
+        // past its first moves however many were played
+        if game.state == 0
+            && game.variant == VARIANT_STANDARD
+            && self.move_count(game_id).is_empty()
+            && (game.player_turn != 0 || self.get_board(game_id) != START_BOARD)
+        {
+            self.uncounted_moves(game_id).set(true);
+        }
+    }
 }
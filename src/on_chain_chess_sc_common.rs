@@ -0,0 +1,108 @@
+use crate::on_chain_chess_sc_storage::{
+    self, Game, GameId, VARIANT_BLIND_OPENING, VARIANT_BUGHOUSE, VARIANT_CHESS960,
+    VARIANT_CRAZYHOUSE,
+};
+use on_chain_chess_core::{
+    chess960_board, chess960_castling_files, pack_board, unpack_board, Board, Position, CASTLE_ALL,
+    CHESS960_POSITIONS, STANDARD_CASTLING_FILES, START_BOARD,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait OnChainChessScCommon: on_chain_chess_sc_storage::OnChainChessScStorage {
+    fn create_game(
+        &self,
+        white_player: ManagedAddress,
+        black_player: ManagedAddress,
+        variant: u8,
+    ) -> GameId {
+        let game_id = self.games().len() + 1;
+        let game = Game::new(
+            game_id,
+            white_player,
+            black_player,
+            self.blockchain().get_block_epoch(),
+            variant,
+        );
+        self.games().push(&game);
+        self.index_game(&game);
+        self.draw_offer(game_id).set(None);
+        self.last_move(game_id).set(None);
+        self.takeback_request(game_id).set(None);
+
+        if variant == VARIANT_CHESS960 {
+            let index = RandomnessSource::new().next_u16_in_range(0, CHESS960_POSITIONS);
+            self.set_board(game_id, &chess960_board(index));
+            self.chess960_index(game_id).set(Some(index));
+            self.castling_rights(game_id).set(CASTLE_ALL);
+            self.castling_files(game_id)
+                .set(chess960_castling_files(index));
+        } else {
+            self.set_board(game_id, &START_BOARD);
+        }
+        if self.has_drops(&game) {
+            self.reserve(game_id, 0).set([0; 5]);
+            self.reserve(game_id, 1).set([0; 5]);
+        }
+
+        game_id
+    }
+
+    fn has_drops(&self, game: &Game<Self::Api>) -> bool {
+        game.variant == VARIANT_BUGHOUSE || game.variant == VARIANT_CRAZYHOUSE
+    }
+
+    // in a blind opening game both sides choose their first move before seeing the other's
+    fn is_blind_opening(&self, game: &Game<Self::Api>) -> bool {
+        game.variant == VARIANT_BLIND_OPENING && self.move_count(game.game_id).get() == 0
+    }
+
+    fn index_game(&self, game: &Game<Self::Api>) {
+        self.player_games(&game.white_player).push(&game.game_id);
+        self.player_games(&game.black_player).push(&game.game_id);
+        if game.state == 0 {
+            self.ongoing_games().insert(game.game_id);
+        } else {
+            self.finished_games().push(&game.game_id);
+        }
+    }
+
+    #[view(getBoard)]
+    fn get_board(&self, game_id: GameId) -> [u8; 64] {
+        unpack_board(&self.board(game_id).get())
+    }
+
+    fn set_board(&self, game_id: GameId, board: &Board) {
+        self.board(game_id).set(pack_board(board));
+    }
+
+    // the board with the side to move and what is left of the castling rights
+    fn game_position(&self, game: &Game<Self::Api>) -> Position {
+        let castling = self.castling_rights(game.game_id).get();
+        let castling_files = if castling == 0 {
+            STANDARD_CASTLING_FILES
+        } else {
+            self.castling_files(game.game_id).get()
+        };
+
+        Position {
+            board: self.get_board(game.game_id),
+            side_to_move: game.player_turn,
+            castling,
+            castling_files,
+            en_passant: None,
+        }
+    }
+
+    // tournament games are settled from the prize pool, not from wagers
+    fn send_wager(&self, game_id: GameId, to: &ManagedAddress, amount: BigUint) {
+        if !self.game_tournament(game_id).is_empty() {
+            return;
+        }
+
+        self.send()
+            .direct_esdt(to, &self.wager_token_id().get(), 0u64, &amount);
+    }
+}
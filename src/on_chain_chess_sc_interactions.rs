@@ -1,27 +1,43 @@
 use crate::{
-    on_chain_chess_sc_requirements,
-    on_chain_chess_sc_storage::{self, Game, GameId},
+    on_chain_chess_sc_common, on_chain_chess_sc_practice,
+    on_chain_chess_sc_requirements::{self, MAX_NOTATION_LEN},
+    on_chain_chess_sc_result_nfts, on_chain_chess_sc_side_bets,
+    on_chain_chess_sc_storage::{
+        self, DrawOffer, Game, GameId, LastMove, PlayedMove, MOVE_FLAG_CASTLING,
+        MOVE_FLAG_DOUBLE_PUSH, MOVE_FLAG_DROP, MOVE_FLAG_PROMOTION, VARIANT_BUGHOUSE,
+        VARIANT_CRAZYHOUSE, VARIANT_KING_OF_THE_HILL, VARIANT_STANDARD, VARIANT_THREE_CHECK,
+    },
+    on_chain_chess_sc_tournament,
+};
+use on_chain_chess_core::{
+    check_drop, color_of, greedy_move, is_castling, is_in_check, is_king_on_the_hill, kind_of,
+    legal_moves, parse_san, parse_uci, play, Bitboards, Board, Move, CHECKS_TO_WIN, DROP_ORIGIN,
+    EMPTY, PAWN,
 };
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-const START_BOARD: [u8; 64] = [
-    9, 11, 10, 8, 7, 10, 11, 9, 12, 12, 12, 12, 12, 12, 12, 12, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
-    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 5, 5, 5, 5, 5, 5, 5, 5, 2, 4, 3, 1,
-    0, 3, 4, 2,
-];
-
 #[multiversx_sc::module]
 pub trait OnChainChessScInteractions:
-    on_chain_chess_sc_requirements::OnChainChessScRequirements
+    on_chain_chess_sc_tournament::OnChainChessScTournament
+    + on_chain_chess_sc_practice::OnChainChessScPractice
+    + on_chain_chess_sc_side_bets::OnChainChessScSideBets
+    + on_chain_chess_sc_result_nfts::OnChainChessScResultNfts
+    + on_chain_chess_sc_common::OnChainChessScCommon
+    + on_chain_chess_sc_requirements::OnChainChessScRequirements
     + on_chain_chess_sc_storage::OnChainChessScStorage
 {
+    // players are only matched with someone waiting for the same variant, standard chess
+    // unless told otherwise
     #[payable("*")]
     #[endpoint(joinGame)]
-    fn join_game(&self) {
+    fn join_game(&self, variant: OptionalValue<u8>) {
         self.require_is_active();
 
+        let variant = variant.into_option().unwrap_or(VARIANT_STANDARD);
+        self.require_known_variant(variant);
+
         let payment = self.call_value().single_esdt();
 
         require!(
@@ -34,26 +50,189 @@ pub trait OnChainChessScInteractions:
         );
 
         let caller = self.blockchain().get_caller();
+        if variant == VARIANT_BUGHOUSE {
+            self.join_bughouse(caller);
+            return;
+        }
 
-        match self.is_player_waiting_opt().get() {
+        let waiting_player = self.waiting_player_for(variant);
+        match waiting_player.get() {
             Some(player) => {
                 require!(player != caller, "You cannot play against yourself");
 
-                let game_id = self.games().len() + 1;
-                let game = Game::new(game_id, player, caller, self.blockchain().get_block_epoch());
-                self.games().push(&game);
-                self.is_player_waiting_opt().set(None);
-                self.draw_offer(game_id).set(None);
-                self.board(game_id).set(START_BOARD);
+                waiting_player.set(None);
+                self.create_game(player, caller, variant);
             }
             None => {
-                self.is_player_waiting_opt().set(Some(caller));
+                waiting_player.set(Some(caller));
             }
         }
     }
 
+    // the fourth player starts a Bughouse match: the first two play the first board, the
+    // last two the second one, and partners play opposite colors, so the first and the
+    // fourth player form a team against the second and the third
+    fn join_bughouse(&self, caller: ManagedAddress) {
+        let mut queue = self.bughouse_queue();
+        require!(
+            !queue.iter().any(|player| player == caller),
+            "You cannot play against yourself"
+        );
+
+        if queue.len() < 3 {
+            queue.push(&caller);
+            return;
+        }
+
+        let first = self.create_game(queue.get(1), queue.get(2), VARIANT_BUGHOUSE);
+        let second = self.create_game(queue.get(3), caller, VARIANT_BUGHOUSE);
+        self.linked_game(first).set(second);
+        self.linked_game(second).set(first);
+        queue.clear();
+    }
+
+    // standard chess keeps the queue it had before there were variants
+    fn waiting_player_for(&self, variant: u8) -> SingleValueMapper<Option<ManagedAddress>> {
+        if variant == VARIANT_STANDARD {
+            self.is_player_waiting_opt()
+        } else {
+            self.waiting_player(variant)
+        }
+    }
+
     #[endpoint(makeMove)]
     fn make_move(&self, game_id: GameId, from: u8, to: u8) {
+        self.play_move(game_id, Move::new(from, to));
+    }
+
+    // a move such as `e2e4`, or `e7e8n` to promote to something other than a queen
+    #[endpoint(makeMoveUci)]
+    fn make_move_uci(&self, game_id: GameId, notation: ManagedBuffer) {
+        self.require_is_active();
+
+        let mut bytes = [0u8; MAX_NOTATION_LEN];
+        let notation = self.require_notation_bytes(&notation, &mut bytes);
+
+        match parse_uci(notation) {
+            Ok(mv) => self.play_move(game_id, mv),
+            Err(error) => sc_panic!(error.as_str()),
+        }
+    }
+
+    // a move such as `Nf3` or `exd5`, looked up on the current board
+    #[endpoint(makeMoveSan)]
+    fn make_move_san(&self, game_id: GameId, notation: ManagedBuffer) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+
+        let mut bytes = [0u8; MAX_NOTATION_LEN];
+        let notation = self.require_notation_bytes(&notation, &mut bytes);
+
+        let position = self.game_position(&self.games().get(game_id));
+        match parse_san(&position, notation) {
+            Ok(mv) => self.play_move(game_id, mv),
+            Err(error) => sc_panic!(error.as_str()),
+        }
+    }
+
+    fn play_move(&self, game_id: GameId, mv: Move) {
+        let game = self.require_caller_to_move(game_id);
+        require!(
+            !self.is_blind_opening(&game),
+            "Moves of the blind opening are committed first"
+        );
+
+        let game_id = game.game_id;
+        self.apply_move(game, mv);
+
+        let game = self.games().get(game_id);
+        if game.state == 0 && self.is_practice_game(&game) {
+            self.play_practice_reply(game);
+        }
+    }
+
+    // the bot calls out a move leaving the player's king in check, then answers with the
+    // legal move winning the most material, and ends the game if the player has no reply
+    fn play_practice_reply(&self, game: Game<Self::Api>) {
+        let game_id = game.game_id;
+        let position = self.game_position(&game);
+        if is_in_check(&position.board, 0) {
+            self.finish_game(game, 2);
+            return;
+        }
+
+        let reply = match greedy_move(&position) {
+            Some(reply) => reply,
+            None if is_in_check(&position.board, 1) => {
+                self.finish_game(game, 1);
+                return;
+            }
+            None => {
+                self.finish_game(game, 3);
+                return;
+            }
+        };
+        self.apply_move(game, reply);
+
+        let game = self.games().get(game_id);
+        let position = self.game_position(&game);
+        if legal_moves(&position).is_empty() {
+            let state = if is_in_check(&position.board, 0) {
+                2
+            } else {
+                3
+            };
+            self.finish_game(game, state);
+        }
+    }
+
+    // puts a piece from the caller's reserve on an empty square, instead of moving one;
+    // `piece` is written as on the board, a white knight is `4`
+    #[endpoint(dropPiece)]
+    fn drop_piece(&self, game_id: GameId, piece: u8, square: u8) {
+        let mut game = self.require_caller_to_move(game_id);
+        require!(
+            self.has_drops(&game),
+            "Pieces cannot be dropped in this game"
+        );
+
+        let mut position = self.game_position(&game);
+        if let Err(error) = check_drop(&position, piece, square) {
+            sc_panic!(error.as_str());
+        }
+
+        let kind = kind_of(piece);
+        self.reserve(game.game_id, game.player_turn)
+            .update(|reserve| {
+                require!(
+                    reserve[kind as usize - 1] > 0,
+                    "You have no such piece to drop"
+                );
+                reserve[kind as usize - 1] -= 1;
+            });
+
+        position.board[square as usize] = piece;
+        self.set_board(game.game_id, &position.board);
+
+        self.record_move(
+            &mut game,
+            LastMove {
+                from: square,
+                to: square,
+                moved_piece: piece,
+                captured_piece: EMPTY,
+                flags: position.castling << 4 | MOVE_FLAG_DROP,
+            },
+            PlayedMove {
+                from: DROP_ORIGIN + kind,
+                to: square,
+                promotion: None,
+            },
+        );
+    }
+
+    // the game the caller is up to move in, with the move deadline pushed back
+    fn require_caller_to_move(&self, game_id: GameId) -> Game<Self::Api> {
         self.require_is_active();
         self.require_game_exists(game_id);
         self.require_game_is_ongoing(game_id);
@@ -61,9 +240,7 @@ pub trait OnChainChessScInteractions:
         let caller = self.blockchain().get_caller();
         let mut game = self.games().get(game_id);
 
-        let color_to_move = game.player_turn;
-
-        let player_to_move = if color_to_move == 0 {
+        let player_to_move = if game.player_turn == 0 {
             game.white_player.clone()
         } else {
             game.black_player.clone()
@@ -82,18 +259,375 @@ pub trait OnChainChessScInteractions:
         );
 
         game.last_move_epoch = current_epoch;
+        game
+    }
 
-        self.require_is_valid_move(game_id, color_to_move, from, to);
+    // plays a move that is up to the side to move, whoever sent it
+    fn apply_move(&self, mut game: Game<Self::Api>, mv: Move) {
+        let game_id = game.game_id;
+        let color_to_move = game.player_turn;
 
-        let mut board = self.board(game_id).get();
-        board[to as usize] = board[from as usize];
-        board[from as usize] = 6;
-        self.board(game_id).set(board);
+        let position = self.game_position(&game);
+        let mv = self.require_is_valid_move(&position, mv);
+        let (from, to) = (mv.from, mv.to);
+
+        let moved_piece = position.board[from as usize];
+        let captured_piece = position.board[to as usize];
+
+        let mut flags = position.castling << 4;
+        if kind_of(moved_piece) == PAWN && (from / 8).abs_diff(to / 8) == 2 {
+            flags |= MOVE_FLAG_DOUBLE_PUSH;
+        }
+        if mv.promotion.is_some() {
+            flags |= MOVE_FLAG_PROMOTION;
+        }
+        if is_castling(&position, mv) {
+            flags |= MOVE_FLAG_CASTLING;
+        }
+
+        let next = play(&position, mv);
+        self.set_board(game_id, &next.board);
+        if next.castling != position.castling {
+            self.castling_rights(game_id).set(next.castling);
+        }
+        if self.has_drops(&game) {
+            self.update_reserves(&game, mv, captured_piece);
+        }
 
-        game.player_turn = 1 - color_to_move;
+        self.record_move(
+            &mut game,
+            LastMove {
+                from,
+                to,
+                moved_piece,
+                captured_piece,
+                flags,
+            },
+            PlayedMove {
+                from,
+                to,
+                promotion: mv.promotion,
+            },
+        );
+
+        if self.wins_by_variant(&game, color_to_move, &next.board) {
+            self.finish_game(game, 1 + color_to_move);
+        }
+    }
+
+    // everything that follows a move or a drop, down to handing the turn over
+    fn record_move(&self, game: &mut Game<Self::Api>, last_move: LastMove, played: PlayedMove) {
+        let game_id = game.game_id;
+        let player_to_move = if game.player_turn == 0 {
+            game.white_player.clone()
+        } else {
+            game.black_player.clone()
+        };
+
+        self.last_move(game_id).set(Some(last_move));
+        self.takeback_request(game_id).set(None);
+
+        let mut move_history = self.move_history(game_id);
+        if move_history.len() as u64 == self.move_count(game_id).get() {
+            move_history.push(&played);
+        }
+
+        // playing on declines a pending draw offer from the opponent
+        if let Some(draw_offer) = self.draw_offer(game_id).get() {
+            if draw_offer.player != player_to_move {
+                self.draw_offer(game_id).set(None);
+            }
+        }
+
+        game.player_turn = 1 - game.player_turn;
+        self.games().set(game_id, game);
+        self.move_count(game_id).update(|count| *count += 1);
+    }
+
+    // a captured piece goes to the pocket of the capturer in Crazyhouse, turning into one of
+    // their own, and in Bughouse to the reserve of their partner, who plays its color on the
+    // other board; promoted pieces go back to being pawns
+    fn update_reserves(&self, game: &Game<Self::Api>, mv: Move, captured_piece: u8) {
+        let promoted_squares = self.promoted_squares(game.game_id);
+        let promoted = promoted_squares.get();
+        let captured_was_promoted = promoted & 1 << mv.to != 0;
+        let moved_is_promoted = promoted & 1 << mv.from != 0 || mv.promotion.is_some();
+
+        let mut next_promoted = promoted & !(1 << mv.from | 1 << mv.to);
+        if moved_is_promoted {
+            next_promoted |= 1 << mv.to;
+        }
+        if next_promoted != promoted {
+            promoted_squares.set(next_promoted);
+        }
+
+        if let Some(color) = color_of(captured_piece) {
+            let kind = if captured_was_promoted {
+                PAWN
+            } else {
+                kind_of(captured_piece)
+            };
+            let (game_id, color) = if game.variant == VARIANT_CRAZYHOUSE {
+                (game.game_id, 1 - color)
+            } else {
+                (self.linked_game(game.game_id).get(), color)
+            };
+            self.reserve(game_id, color)
+                .update(|reserve| reserve[kind as usize - 1] += 1);
+        }
+    }
+
+    // `hash` is the keccak256 of the origin and destination squares followed by a salt
+    #[endpoint(commitMove)]
+    fn commit_move(&self, game_id: GameId, hash: ManagedByteArray<32>) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+        self.require_game_is_ongoing(game_id);
+
+        let mut game = self.games().get(game_id);
+        require!(
+            self.is_blind_opening(&game),
+            "There is no move to commit to"
+        );
+
+        let color = self.require_player_color(&game);
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch <= game.last_move_epoch + 1,
+            "You took too long to make a move"
+        );
+        require!(
+            self.move_commitment(game_id, color).is_empty(),
+            "You already committed a move"
+        );
+
+        self.move_commitment(game_id, color).set(hash);
+        game.last_move_epoch = current_epoch;
         self.games().set(game_id, &game);
     }
 
+    // once both sides committed, each one shows their move, the two are played together
+    // after the second reveal
+    #[endpoint(revealMove)]
+    fn reveal_move(&self, game_id: GameId, from: u8, to: u8, salt: ManagedBuffer) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+        self.require_game_is_ongoing(game_id);
+
+        let mut game = self.games().get(game_id);
+        require!(self.is_blind_opening(&game), "There is no move to reveal");
+
+        let color = self.require_player_color(&game);
+        require!(
+            !self.move_commitment(game_id, 0).is_empty()
+                && !self.move_commitment(game_id, 1).is_empty(),
+            "Both players have to commit first"
+        );
+        require!(
+            self.revealed_move(game_id, color).is_empty(),
+            "You already revealed your move"
+        );
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch <= game.last_move_epoch + 1,
+            "You took too long to make a move"
+        );
+
+        let mut preimage = ManagedBuffer::new();
+        preimage.append_bytes(&[from, to]);
+        preimage.append(&salt);
+        require!(
+            self.crypto().keccak256(&preimage) == self.move_commitment(game_id, color).get(),
+            "Move does not match the commitment"
+        );
+
+        // no first move of white can block or uncover a first move of black, so both are
+        // checked against the start position
+        let mut position = self.game_position(&game);
+        position.side_to_move = color;
+        let mv = self.require_is_valid_move(&position, Move::new(from, to));
+
+        self.revealed_move(game_id, color).set(PlayedMove {
+            from: mv.from,
+            to: mv.to,
+            promotion: mv.promotion,
+        });
+        game.last_move_epoch = current_epoch;
+
+        if self.revealed_move(game_id, 1 - color).is_empty() {
+            self.games().set(game_id, &game);
+            return;
+        }
+
+        for color in 0..2 {
+            let revealed = self.revealed_move(game_id, color).take();
+            self.move_commitment(game_id, color).clear();
+
+            let game = self.games().get(game_id);
+            self.apply_move(
+                game,
+                Move {
+                    from: revealed.from,
+                    to: revealed.to,
+                    promotion: revealed.promotion,
+                },
+            );
+        }
+    }
+
+    fn require_player_color(&self, game: &Game<Self::Api>) -> u8 {
+        let caller = self.blockchain().get_caller();
+        if caller == game.white_player {
+            0
+        } else if caller == game.black_player {
+            1
+        } else {
+            sc_panic!("You are not part of the game")
+        }
+    }
+
+    // King of the Hill and Three-Check games end as soon as a move reaches their goal; a move
+    // leaving the own king in check neither wins nor counts, it is up to the opponent to call
+    // it out
+    fn wins_by_variant(&self, game: &Game<Self::Api>, mover: u8, board: &Board) -> bool {
+        if game.variant != VARIANT_KING_OF_THE_HILL && game.variant != VARIANT_THREE_CHECK {
+            return false;
+        }
+
+        let bitboards = Bitboards::from_board(board);
+        if bitboards.is_in_check(mover) {
+            return false;
+        }
+
+        if game.variant == VARIANT_KING_OF_THE_HILL {
+            is_king_on_the_hill(board, mover)
+        } else {
+            bitboards.is_in_check(1 - mover)
+                && self.checks_given(game.game_id, mover).update(|checks| {
+                    *checks += 1;
+                    *checks >= CHECKS_TO_WIN
+                })
+        }
+    }
+
+    // the player that just moved asks to undo their last move
+    #[endpoint(requestTakeback)]
+    fn request_takeback(&self, game_id: GameId) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+        self.require_game_is_ongoing(game_id);
+
+        let caller = self.blockchain().get_caller();
+        let game = self.games().get(game_id);
+
+        require!(
+            caller == game.white_player || caller == game.black_player,
+            "You are not part of the game"
+        );
+
+        let player_to_move = if game.player_turn == 0 {
+            &game.white_player
+        } else {
+            &game.black_player
+        };
+
+        require!(
+            player_to_move != &caller,
+            "You can only take back your own move"
+        );
+        require!(
+            self.last_move(game_id).get().is_some(),
+            "There is no move to take back"
+        );
+        require!(
+            !self.has_drops(&game),
+            "Moves cannot be taken back in this game"
+        );
+
+        self.takeback_request(game_id).set(Some(caller));
+    }
+
+    #[endpoint(acceptTakeback)]
+    fn accept_takeback(&self, game_id: GameId) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+        self.require_game_is_ongoing(game_id);
+
+        let caller = self.blockchain().get_caller();
+        let mut game = self.games().get(game_id);
+
+        require!(
+            caller == game.white_player || caller == game.black_player,
+            "You are not part of the game"
+        );
+
+        let takeback_request = self.takeback_request(game_id).get();
+        require!(
+            takeback_request.is_some() && takeback_request.unwrap() != caller,
+            "There is no takeback request from your opponent"
+        );
+
+        let last_move = self.last_move(game_id).get();
+        require!(last_move.is_some(), "There is no move to take back");
+        let last_move = last_move.unwrap();
+
+        let mut board = self.get_board(game_id);
+
+        // a check given by the move no longer counts
+        let mover = 1 - game.player_turn;
+        let bitboards = Bitboards::from_board(&board);
+        if game.variant == VARIANT_THREE_CHECK
+            && bitboards.is_in_check(1 - mover)
+            && !bitboards.is_in_check(mover)
+        {
+            self.checks_given(game_id, mover)
+                .update(|checks| *checks -= 1);
+        }
+
+        if last_move.flags & MOVE_FLAG_CASTLING != 0 {
+            // the king went onto its rook, or two squares sideways with the rook in a corner
+            let row = last_move.from / 8 * 8;
+            let (king_to, rook_to, corner) = if last_move.to % 8 > last_move.from % 8 {
+                (6, 5, 7)
+            } else {
+                (2, 3, 0)
+            };
+            let rook_from = if last_move.captured_piece == EMPTY {
+                row + corner
+            } else {
+                last_move.to
+            };
+
+            let rook = board[(row + rook_to) as usize];
+            board[(row + king_to) as usize] = EMPTY;
+            board[(row + rook_to) as usize] = EMPTY;
+            board[last_move.from as usize] = last_move.moved_piece;
+            board[rook_from as usize] = rook;
+        } else {
+            board[last_move.from as usize] = last_move.moved_piece;
+            board[last_move.to as usize] = last_move.captured_piece;
+        }
+        self.set_board(game_id, &board);
+        if last_move.flags >> 4 != self.castling_rights(game_id).get() {
+            self.castling_rights(game_id).set(last_move.flags >> 4);
+        }
+
+        self.last_move(game_id).set(None);
+        self.takeback_request(game_id).set(None);
+        self.draw_offer(game_id).set(None);
+
+        let mut move_history = self.move_history(game_id);
+        if move_history.len() as u64 == self.move_count(game_id).get() {
+            move_history.swap_remove(move_history.len());
+        }
+
+        game.player_turn = 1 - game.player_turn;
+        game.last_move_epoch = self.blockchain().get_block_epoch();
+        self.games().set(game_id, &game);
+        self.move_count(game_id).update(|count| *count -= 1);
+    }
+
     #[endpoint(draw)]
     fn draw(&self, game_id: GameId) {
         self.require_is_active();
@@ -101,7 +635,7 @@ pub trait OnChainChessScInteractions:
         self.require_game_is_ongoing(game_id);
 
         let caller = self.blockchain().get_caller();
-        let mut game = self.games().get(game_id);
+        let game = self.games().get(game_id);
 
         require!(
             caller == game.white_player || caller == game.black_player,
@@ -110,32 +644,32 @@ pub trait OnChainChessScInteractions:
 
         let draw_offer = self.draw_offer(game_id).get();
         if draw_offer.is_none() {
-            self.draw_offer(game_id).set(Some(caller));
-        } else if draw_offer.unwrap() != caller {
+            self.draw_offer(game_id).set(Some(DrawOffer {
+                player: caller,
+                move_number: self.move_count(game_id).get(),
+                timestamp: self.blockchain().get_block_timestamp(),
+            }));
+        } else if draw_offer.unwrap().player != caller {
             self.draw_offer(game_id).set(None);
+            self.finish_game(game, 3);
+        }
+    }
 
-            game.state = 3;
-            self.games().set(game_id, &game);
+    #[endpoint(withdrawDrawOffer)]
+    fn withdraw_draw_offer(&self, game_id: GameId) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+        self.require_game_is_ongoing(game_id);
 
-            self.send().direct_esdt(
-                &game.white_player,
-                &self.wager_token_id().get(),
-                0u64,
-                &self.wager_amount().get(),
-            );
+        let caller = self.blockchain().get_caller();
+        let draw_offer = self.draw_offer(game_id).get();
 
-            self.send().direct_esdt(
-                &game.black_player,
-                &self.wager_token_id().get(),
-                0u64,
-                &self.wager_amount().get(),
-            );
+        require!(
+            draw_offer.is_some() && draw_offer.unwrap().player == caller,
+            "You have no draw offer to withdraw"
+        );
 
-            self.score(game.white_player.clone())
-                .set(self.score(game.white_player).get() + 1);
-            self.score(game.black_player.clone())
-                .set(self.score(game.black_player).get() + 1);
-        }
+        self.draw_offer(game_id).set(None);
     }
 
     // checks it opponent finished their move while in check
@@ -146,7 +680,7 @@ pub trait OnChainChessScInteractions:
         self.require_game_is_ongoing(game_id);
 
         let caller = self.blockchain().get_caller();
-        let mut game = self.games().get(game_id);
+        let game = self.games().get(game_id);
 
         require!(
             caller == game.white_player || caller == game.black_player,
@@ -162,18 +696,8 @@ pub trait OnChainChessScInteractions:
         require!(player_to_move == caller, "You just moved");
         self.require_king_in_check(1 - game.player_turn, game_id);
 
-        game.state = 1 + game.player_turn;
-        self.games().set(game_id, &game);
-
-        self.send().direct_esdt(
-            &player_to_move,
-            &self.wager_token_id().get(),
-            0u64,
-            &(self.wager_amount().get() * BigUint::from(2u64)),
-        );
-
-        self.score(player_to_move.clone())
-            .set(self.score(player_to_move).get() + 2);
+        let state = 1 + game.player_turn;
+        self.finish_game(game, state);
     }
 
     #[endpoint(signalInactivity)]
@@ -182,21 +706,39 @@ pub trait OnChainChessScInteractions:
         self.require_game_exists(game_id);
         self.require_game_is_ongoing(game_id);
 
-        let caller = self.blockchain().get_caller();
-        let mut game = self.games().get(game_id);
+        let mut caller = self.blockchain().get_caller();
+        let game = self.games().get(game_id);
+
+        // the bot cannot send transactions, the owner claims an abandoned practice game for it
+        if self.is_practice_game(&game) && caller == self.blockchain().get_owner_address() {
+            caller = game.black_player.clone();
+        }
 
         require!(
             caller == game.white_player || caller == game.black_player,
             "You are not part of the game"
         );
 
-        let player_to_move = if game.player_turn == 0 {
-            game.white_player.clone()
+        let caller_color = if caller == game.white_player { 0 } else { 1 };
+
+        if self.is_blind_opening(&game) {
+            // whoever committed, or revealed, while the opponent did not is owed the win
+            let revealing = !self.move_commitment(game_id, 0).is_empty()
+                && !self.move_commitment(game_id, 1).is_empty();
+            let done = |color| {
+                if revealing {
+                    !self.revealed_move(game_id, color).is_empty()
+                } else {
+                    !self.move_commitment(game_id, color).is_empty()
+                }
+            };
+            require!(
+                done(caller_color) && !done(1 - caller_color),
+                "You are up to move"
+            );
         } else {
-            game.black_player.clone()
-        };
-
-        require!(player_to_move != caller, "You are up to move");
+            require!(game.player_turn != caller_color, "You are up to move");
+        }
 
         let current_epoch = self.blockchain().get_block_epoch();
 
@@ -205,17 +747,7 @@ pub trait OnChainChessScInteractions:
             "Opponent can still make a move"
         );
 
-        game.state = 2 - game.player_turn;
-        self.games().set(game_id, &game);
-
-        self.send().direct_esdt(
-            &caller,
-            &self.wager_token_id().get(),
-            0u64,
-            &(self.wager_amount().get() * BigUint::from(2u64)),
-        );
-
-        self.score(caller.clone()).set(self.score(caller).get() + 2);
+        self.finish_game(game, 1 + caller_color);
     }
 
     #[endpoint(resign)]
@@ -225,7 +757,7 @@ pub trait OnChainChessScInteractions:
         self.require_game_is_ongoing(game_id);
 
         let caller = self.blockchain().get_caller();
-        let mut game = self.games().get(game_id);
+        let game = self.games().get(game_id);
 
         require!(
             caller == game.white_player || caller == game.black_player,
@@ -233,30 +765,102 @@ pub trait OnChainChessScInteractions:
         );
 
         if caller == game.white_player {
-            game.state = 2;
+            self.finish_game(game, 2);
+        } else {
+            self.finish_game(game, 1);
+        }
+    }
 
-            self.send().direct_esdt(
-                &game.black_player,
-                &self.wager_token_id().get(),
-                0u64,
-                &(self.wager_amount().get() * BigUint::from(2u64)),
-            );
+    // either player can walk away before both sides made their first move
+    #[endpoint(abortGame)]
+    fn abort_game(&self, game_id: GameId) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+        self.require_game_is_ongoing(game_id);
 
-            self.score(game.black_player.clone())
-                .set(self.score(game.black_player.clone()).get() + 2);
-        } else {
-            game.state = 1;
+        let caller = self.blockchain().get_caller();
+        let game = self.games().get(game_id);
 
-            self.send().direct_esdt(
-                &game.white_player,
-                &self.wager_token_id().get(),
-                0u64,
-                &(self.wager_amount().get() * BigUint::from(2u64)),
-            );
+        require!(
+            caller == game.white_player || caller == game.black_player,
+            "You are not part of the game"
+        );
+        let linked_game = self.linked_game(game_id).get();
+        require!(
+            self.move_count(game_id).get() < 2
+                && !self.uncounted_moves(game_id).get()
+                && (linked_game == 0 || self.move_count(linked_game).get() < 2),
+            "Game can no longer be aborted"
+        );
+        require!(
+            self.game_tournament(game_id).is_empty(),
+            "Tournament games cannot be aborted"
+        );
 
-            self.score(game.white_player.clone())
-                .set(self.score(game.white_player.clone()).get() + 2);
-        }
+        self.draw_offer(game_id).set(None);
+        self.finish_game(game, 4);
+    }
+
+    // settles wagers and scores, then lets the tournament know about the result
+    fn finish_game(&self, mut game: Game<Self::Api>, state: u8) {
+        let game_id = game.game_id;
+        game.state = state;
         self.games().set(game_id, &game);
+        self.ongoing_games().swap_remove(&game_id);
+        self.finished_games().push(&game_id);
+        self.close_side_pools(&game);
+        self.mint_result_nfts(&game);
+
+        // practice stakes were paid to the house pool, whatever the outcome
+        if self.is_practice_game(&game) {
+            return;
+        }
+
+        match state {
+            1 => {
+                self.send_wager(
+                    game_id,
+                    &game.white_player,
+                    self.wager_amount().get() * BigUint::from(2u64),
+                );
+                self.score(game.white_player.clone())
+                    .update(|score| *score += 2);
+            }
+            2 => {
+                self.send_wager(
+                    game_id,
+                    &game.black_player,
+                    self.wager_amount().get() * BigUint::from(2u64),
+                );
+                self.score(game.black_player.clone())
+                    .update(|score| *score += 2);
+            }
+            _ => {
+                self.send_wager(game_id, &game.white_player, self.wager_amount().get());
+                self.send_wager(game_id, &game.black_player, self.wager_amount().get());
+
+                // an aborted game does not count
+                if state == 3 {
+                    self.score(game.white_player.clone())
+                        .update(|score| *score += 1);
+                    self.score(game.black_player.clone())
+                        .update(|score| *score += 1);
+                }
+            }
+        }
+
+        self.tournament_game_ended(game_id);
+
+        // both boards of a Bughouse match end together, with the partner winning or losing
+        // alongside
+        let linked_game = self.linked_game(game_id).get();
+        if linked_game != 0 && self.games().get(linked_game).state == 0 {
+            let linked_state = match state {
+                1 => 2,
+                2 => 1,
+                _ => state,
+            };
+            self.finish_game(self.games().get(linked_game), linked_state);
+        }
     }
 }
@@ -1,4 +1,4 @@
-use crate::on_chain_chess_sc_storage;
+use crate::{on_chain_chess_sc_side_bets::MAX_SIDE_BET_FEE, on_chain_chess_sc_storage};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -16,6 +16,10 @@ pub trait OnChainChessScAdminInteractions:
     #[only_owner]
     #[endpoint(unpause)]
     fn unpause(&self) {
+        require!(
+            self.migrated_games().get() >= self.migration_end().get(),
+            "Games are still being migrated"
+        );
         self.is_paused().set(false);
     }
 
@@ -25,4 +29,50 @@ pub trait OnChainChessScAdminInteractions:
         self.wager_token_id().set_if_empty(wager_token_id);
         self.wager_amount().set_if_empty(wager_amount);
     }
+
+    #[only_owner]
+    #[endpoint(addTournamentOrganizer)]
+    fn add_tournament_organizer(&self, organizer: ManagedAddress) {
+        self.tournament_organizers().insert(organizer);
+    }
+
+    #[only_owner]
+    #[endpoint(removeTournamentOrganizer)]
+    fn remove_tournament_organizer(&self, organizer: ManagedAddress) {
+        self.tournament_organizers().swap_remove(&organizer);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxPracticeWager)]
+    fn set_max_practice_wager(&self, amount: BigUint) {
+        self.max_practice_wager().set(amount);
+    }
+
+    #[only_owner]
+    #[endpoint(withdrawHousePool)]
+    fn withdraw_house_pool(&self, amount: BigUint) {
+        require!(
+            amount <= self.house_pool().get(),
+            "Not enough tokens in the house pool"
+        );
+
+        self.house_pool().update(|pool| *pool -= &amount);
+        self.send().direct_esdt(
+            &self.blockchain().get_caller(),
+            &self.wager_token_id().get(),
+            0u64,
+            &amount,
+        );
+    }
+
+    // in basis points, what is left of the side pools always goes to the bettors
+    #[only_owner]
+    #[endpoint(setSideBetFee)]
+    fn set_side_bet_fee(&self, fee: u32) {
+        require!(
+            fee < MAX_SIDE_BET_FEE,
+            "Fee must be below 10000 basis points"
+        );
+        self.side_bet_fee().set(fee);
+    }
 }
@@ -0,0 +1,50 @@
+use crate::{
+    on_chain_chess_sc_common, on_chain_chess_sc_requirements,
+    on_chain_chess_sc_storage::{self, Game, GameId, VARIANT_STANDARD},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait OnChainChessScPractice:
+    on_chain_chess_sc_common::OnChainChessScCommon
+    + on_chain_chess_sc_requirements::OnChainChessScRequirements
+    + on_chain_chess_sc_storage::OnChainChessScStorage
+{
+    // starts a game with white against the contract itself, which answers every move right
+    // away; the stake is optional, up to `maxPracticeWager`, and is paid to the house pool:
+    // the bot always answers the same way, so a line beating it could be replayed for profit
+    #[payable("*")]
+    #[endpoint(startPracticeGame)]
+    fn start_practice_game(&self) -> GameId {
+        self.require_is_active();
+
+        let payment = self.call_value().egld_or_single_esdt();
+        if payment.amount != 0 {
+            require!(
+                payment.token_identifier
+                    == EgldOrEsdtTokenIdentifier::esdt(self.wager_token_id().get()),
+                "Wrong wager token identifier"
+            );
+        }
+        require!(
+            payment.amount <= self.max_practice_wager().get(),
+            "Practice wager is too high"
+        );
+
+        self.house_pool().update(|pool| *pool += &payment.amount);
+
+        let game_id = self.create_game(
+            self.blockchain().get_caller(),
+            self.blockchain().get_sc_address(),
+            VARIANT_STANDARD,
+        );
+        self.practice_wager(game_id).set(&payment.amount);
+        game_id
+    }
+
+    fn is_practice_game(&self, game: &Game<Self::Api>) -> bool {
+        game.black_player == self.blockchain().get_sc_address()
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    on_chain_chess_sc_requirements,
+    on_chain_chess_sc_storage::{self, Game, GameId},
+};
+use on_chain_chess_core::PackedBoard;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+// the attributes of a game result token, `board` is packed as in the `board` storage
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct GameResult<M: ManagedTypeApi> {
+    pub game_id: GameId,
+    pub white_player: ManagedAddress<M>,
+    pub black_player: ManagedAddress<M>,
+    pub outcome: u8,
+    pub move_count: u64,
+    pub board: PackedBoard,
+}
+
+#[multiversx_sc::module]
+pub trait OnChainChessScResultNfts:
+    on_chain_chess_sc_requirements::OnChainChessScRequirements
+    + on_chain_chess_sc_storage::OnChainChessScStorage
+{
+    // issues the semi-fungible collection game results are minted in, with the contract
+    // allowed to create and add quantity; the payment is the issue cost
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueResultCollection)]
+    fn issue_result_collection(&self, name: ManagedBuffer, ticker: ManagedBuffer) {
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.result_collection().issue_and_set_all_roles(
+            EsdtTokenType::SemiFungible,
+            issue_cost,
+            name,
+            ticker,
+            0,
+            Some(self.callbacks().result_collection_issued()),
+        );
+    }
+
+    #[callback]
+    fn result_collection_issued(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.result_collection().set_token_id(token_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                // the issue cost comes back with the error
+                self.result_collection().clear();
+                let issue_cost = self.call_value().egld_value().clone_value();
+                if issue_cost > 0 {
+                    self.send()
+                        .direct_egld(&self.blockchain().get_owner_address(), &issue_cost);
+                }
+            }
+        }
+    }
+
+    // players who opt in get a token for every game they finish from then on
+    #[endpoint(setWantsResultNft)]
+    fn set_wants_result_nft(&self, wants: bool) {
+        self.require_is_active();
+
+        let caller = self.blockchain().get_caller();
+        if wants {
+            self.wants_result_nft(&caller).set(true);
+        } else {
+            self.wants_result_nft(&caller).clear();
+        }
+    }
+
+    // one nonce per game, with a token for each player that opted in
+    fn mint_result_nfts(&self, game: &Game<Self::Api>) {
+        if game.state == 4 || !self.result_collection().get_token_state().is_set() {
+            return;
+        }
+
+        let mut recipients = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for player in [&game.white_player, &game.black_player] {
+            if self.wants_result_nft(player).get() {
+                recipients.push(player.clone());
+            }
+        }
+        if recipients.is_empty() {
+            return;
+        }
+
+        let attributes = GameResult {
+            game_id: game.game_id,
+            white_player: game.white_player.clone(),
+            black_player: game.black_player.clone(),
+            outcome: game.state,
+            move_count: self.move_count(game.game_id).get(),
+            board: self.board(game.game_id).get(),
+        };
+        let token = self
+            .result_collection()
+            .nft_create(BigUint::from(recipients.len()), &attributes);
+        for player in recipients.iter() {
+            self.send().direct_esdt(
+                &player,
+                &token.token_identifier,
+                token.token_nonce,
+                &1u32.into(),
+            );
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    on_chain_chess_sc_common, on_chain_chess_sc_requirements,
+    on_chain_chess_sc_storage::{self, Game, GameId},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+// side bets are taken during the first ten moves, five of each player
+pub const SIDE_BET_MOVES: u64 = 10;
+
+pub const MAX_SIDE_BET_FEE: u32 = 10_000;
+
+#[multiversx_sc::module]
+pub trait OnChainChessScSideBets:
+    on_chain_chess_sc_common::OnChainChessScCommon
+    + on_chain_chess_sc_requirements::OnChainChessScRequirements
+    + on_chain_chess_sc_storage::OnChainChessScStorage
+{
+    // outcome: 1 for white winning, 2 for black winning, 3 for a draw, as in `Game.state`
+    #[payable("*")]
+    #[endpoint(placeSideBet)]
+    fn place_side_bet(&self, game_id: GameId, outcome: u8) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+        self.require_game_is_ongoing(game_id);
+        require!((1..=3).contains(&outcome), "Unknown outcome");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.plays_in(&caller, game_id),
+            "Players cannot bet on their own games"
+        );
+        require!(
+            self.move_count(game_id).get() < SIDE_BET_MOVES && !self.uncounted_moves(game_id).get(),
+            "Side bets are closed"
+        );
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.wager_token_id().get(),
+            "Wrong wager token identifier"
+        );
+
+        self.side_bet(game_id, outcome, &caller)
+            .update(|bet| *bet += &payment.amount);
+        self.side_pool(game_id, outcome)
+            .update(|pool| *pool += payment.amount);
+    }
+
+    // pays out the caller's share once the game is over
+    #[endpoint(claimSideBet)]
+    fn claim_side_bet(&self, game_id: GameId) {
+        self.require_is_active();
+        self.require_game_exists(game_id);
+
+        let game = self.games().get(game_id);
+        require!(game.state != 0, "Game is still ongoing");
+
+        let caller = self.blockchain().get_caller();
+        let payout = self.side_payout(game_id).get();
+        let amount = if payout == 0 {
+            // nobody backed the outcome or the game was aborted, every stake goes back
+            let mut stakes = BigUint::zero();
+            for outcome in 1..=3 {
+                stakes += self.side_bet(game_id, outcome, &caller).take();
+            }
+            stakes
+        } else {
+            let stake = self.side_bet(game_id, game.state, &caller).take();
+            stake * payout / self.side_pool(game_id, game.state).get()
+        };
+        require!(amount > 0, "Nothing to claim");
+
+        self.send()
+            .direct_esdt(&caller, &self.wager_token_id().get(), 0u64, &amount);
+    }
+
+    // a Bughouse player has a stake in both boards of the match
+    fn plays_in(&self, address: &ManagedAddress, game_id: GameId) -> bool {
+        let linked_game = self.linked_game(game_id).get();
+        [game_id, linked_game]
+            .into_iter()
+            .filter(|&game_id| game_id != 0)
+            .map(|game_id| self.games().get(game_id))
+            .any(|game| &game.white_player == address || &game.black_player == address)
+    }
+
+    // takes the fee off the pools of a decided game and leaves the rest to those who backed
+    // the outcome
+    fn close_side_pools(&self, game: &Game<Self::Api>) {
+        if !(1..=3).contains(&game.state) || self.side_pool(game.game_id, game.state).is_empty() {
+            return;
+        }
+
+        let mut total = BigUint::zero();
+        for outcome in 1..=3 {
+            total += self.side_pool(game.game_id, outcome).get();
+        }
+        let fee = &total * self.side_bet_fee().get() / MAX_SIDE_BET_FEE;
+
+        self.house_pool().update(|pool| *pool += &fee);
+        self.side_payout(game.game_id).set(total - fee);
+    }
+}
@@ -1,9 +1,26 @@
+use on_chain_chess_core::{PackedBoard, Reserve};
+
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 pub type GameId = usize;
+pub type TournamentId = usize;
+
+pub const MOVE_FLAG_DOUBLE_PUSH: u8 = 1;
+pub const MOVE_FLAG_PROMOTION: u8 = 2;
+pub const MOVE_FLAG_CASTLING: u8 = 4;
+pub const MOVE_FLAG_DROP: u8 = 8;
 
-#[derive(TypeAbi, NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode)]
+pub const VARIANT_STANDARD: u8 = 0;
+pub const VARIANT_CHESS960: u8 = 1;
+pub const VARIANT_KING_OF_THE_HILL: u8 = 2;
+pub const VARIANT_THREE_CHECK: u8 = 3;
+pub const VARIANT_BLIND_OPENING: u8 = 4;
+pub const VARIANT_BUGHOUSE: u8 = 5;
+pub const VARIANT_CRAZYHOUSE: u8 = 6;
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode)]
 pub struct Game<M: ManagedTypeApi> {
     pub game_id: GameId,
     pub white_player: ManagedAddress<M>,
@@ -11,14 +28,19 @@ pub struct Game<M: ManagedTypeApi> {
     pub player_turn: u8,
     pub last_move_epoch: u64,
     pub state: u8,
+    pub variant: u8,
 }
 
+// a game as older versions stored it, without the variant
+pub type LegacyGame<M> = (GameId, ManagedAddress<M>, ManagedAddress<M>, u8, u64, u8);
+
 impl<M: ManagedTypeApi> Game<M> {
     pub fn new(
         game_id: GameId,
         white_player: ManagedAddress<M>,
         black_player: ManagedAddress<M>,
         last_move_epoch: u64,
+        variant: u8,
     ) -> Self {
         Game {
             game_id,
@@ -27,10 +49,58 @@ impl<M: ManagedTypeApi> Game<M> {
             player_turn: 0u8,
             last_move_epoch,
             state: 0u8,
+            variant,
         }
     }
 }
 
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode)]
+pub struct Tournament<M: ManagedTypeApi> {
+    pub tournament_id: TournamentId,
+    pub organizer: ManagedAddress<M>,
+    pub entry_fee: BigUint<M>,
+    pub rounds: u32,
+    pub current_round: u32,
+    pub pairing_system: u8,
+    pub draw_rule: u8,
+    pub payout_table: ManagedVec<M, u32>,
+    pub prize_pool: BigUint<M>,
+    pub state: u8,
+}
+
+// everything needed to take the last move back, the upper four bits of `flags` hold the
+// castling rights from before the move
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode, Clone, Copy)]
+pub struct LastMove {
+    pub from: u8,
+    pub to: u8,
+    pub moved_piece: u8,
+    pub captured_piece: u8,
+    pub flags: u8,
+}
+
+// a move as it was sent, `promotion` is the kind a pawn turned into
+#[type_abi]
+#[derive(
+    NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode, Clone, Copy, ManagedVecItem,
+)]
+pub struct PlayedMove {
+    pub from: u8,
+    pub to: u8,
+    pub promotion: Option<u8>,
+}
+
+// who offered a draw, after how many moves and when
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, PartialEq, Debug, TopEncode, TopDecode)]
+pub struct DrawOffer<M: ManagedTypeApi> {
+    pub player: ManagedAddress<M>,
+    pub move_number: u64,
+    pub timestamp: u64,
+}
+
 #[multiversx_sc::module]
 pub trait OnChainChessScStorage {
     #[view(isPaused)]
@@ -53,15 +123,248 @@ pub trait OnChainChessScStorage {
     #[storage_mapper("isPlayerWaitingOpt")]
     fn is_player_waiting_opt(&self) -> SingleValueMapper<Option<ManagedAddress>>;
 
+    // players waiting for an opponent in a variant other than standard chess
+    #[view(getWaitingPlayer)]
+    #[storage_mapper("waitingPlayer")]
+    fn waiting_player(&self, variant: u8) -> SingleValueMapper<Option<ManagedAddress>>;
+
+    // players waiting for enough others to fill both boards of a Bughouse match
+    #[view(getBughouseQueue)]
+    #[storage_mapper("bughouseQueue")]
+    fn bughouse_queue(&self) -> VecMapper<ManagedAddress>;
+
+    // the storage layout the games were migrated to, `0` for the versions before it was kept
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    // the last game stored by the version before the latest upgrade
+    #[storage_mapper("migrationEnd")]
+    fn migration_end(&self) -> SingleValueMapper<GameId>;
+
+    // games up to this one are migrated to the storage of the current version
+    #[view(getMigratedGames)]
+    #[storage_mapper("migratedGames")]
+    fn migrated_games(&self) -> SingleValueMapper<GameId>;
+
     #[view(getGames)]
     #[storage_mapper("games")]
     fn games(&self) -> VecMapper<Game<Self::Api>>;
 
-    #[view(getBoard)]
+    // older versions stored games without a variant, `upgrade` adds it
+    #[storage_mapper("games.item")]
+    fn legacy_game(&self, game_id: GameId) -> SingleValueMapper<LegacyGame<Self::Api>>;
+
+    // the ids of every game a player took part in, oldest first
+    #[storage_mapper("playerGames")]
+    fn player_games(&self, player: &ManagedAddress) -> VecMapper<GameId>;
+
+    #[storage_mapper("ongoingGames")]
+    fn ongoing_games(&self) -> UnorderedSetMapper<GameId>;
+
+    // the ids of the games that are over, in the order they ended
+    #[storage_mapper("finishedGames")]
+    fn finished_games(&self) -> VecMapper<GameId>;
+
+    // two squares per byte, `getBoard` unpacks it
+    #[storage_mapper("board")]
+    fn board(&self, game_id: GameId) -> SingleValueMapper<PackedBoard>;
+
+    // older versions stored a byte per square, `upgrade` repacks those
     #[storage_mapper("board")]
-    fn board(&self, game_id: GameId) -> SingleValueMapper<[u8; 64]>;
+    fn legacy_board(&self, game_id: GameId) -> SingleValueMapper<[u8; 64]>;
+
+    // older versions only stored who offered a draw, `upgrade` adds when
+    #[storage_mapper("drawOffer")]
+    fn legacy_draw_offer(&self, game_id: GameId) -> SingleValueMapper<Option<ManagedAddress>>;
+
+    // the sides that may still castle, only Chess960 games start with any
+    #[view(getCastlingRights)]
+    #[storage_mapper("castlingRights")]
+    fn castling_rights(&self, game_id: GameId) -> SingleValueMapper<u8>;
+
+    // the files of the queen side and king side rooks of a Chess960 game
+    #[storage_mapper("castlingFiles")]
+    fn castling_files(&self, game_id: GameId) -> SingleValueMapper<[u8; 2]>;
+
+    // the start position of a Chess960 game, numbered as in `chess960_board`; none for games
+    // started before it was recorded
+    #[view(getChess960Index)]
+    #[storage_mapper("chess960Index")]
+    fn chess960_index(&self, game_id: GameId) -> SingleValueMapper<Option<u16>>;
+
+    // the checks each side gave in a Three-Check game
+    #[view(getChecksGiven)]
+    #[storage_mapper("checksGiven")]
+    fn checks_given(&self, game_id: GameId, color: u8) -> SingleValueMapper<u8>;
+
+    // the hash of the first move and salt each side of a blind opening game committed to
+    #[view(getMoveCommitment)]
+    #[storage_mapper("moveCommitment")]
+    fn move_commitment(
+        &self,
+        game_id: GameId,
+        color: u8,
+    ) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    // the first moves revealed so far, played once both sides revealed theirs
+    #[view(getRevealedMove)]
+    #[storage_mapper("revealedMove")]
+    fn revealed_move(&self, game_id: GameId, color: u8) -> SingleValueMapper<PlayedMove>;
+
+    // the other board of a Bughouse match
+    #[view(getLinkedGame)]
+    #[storage_mapper("linkedGame")]
+    fn linked_game(&self, game_id: GameId) -> SingleValueMapper<GameId>;
+
+    // how many queens, rooks, bishops, knights and pawns a side can drop, the partner's
+    // captures in Bughouse and the own ones, the pocket, in Crazyhouse
+    #[view(getReserve)]
+    #[storage_mapper("reserve")]
+    fn reserve(&self, game_id: GameId, color: u8) -> SingleValueMapper<Reserve>;
+
+    // one bit per square holding a promoted piece, which goes back to being a pawn when
+    // it is captured
+    #[storage_mapper("promotedSquares")]
+    fn promoted_squares(&self, game_id: GameId) -> SingleValueMapper<u64>;
+
+    #[view(getMoveCount)]
+    #[storage_mapper("moveCount")]
+    fn move_count(&self, game_id: GameId) -> SingleValueMapper<u64>;
+
+    // set on games that were under way before moves were counted, `move_count` starts
+    // from zero on those
+    #[storage_mapper("uncountedMoves")]
+    fn uncounted_moves(&self, game_id: GameId) -> SingleValueMapper<bool>;
 
     #[view(getDrawOffer)]
     #[storage_mapper("drawOffer")]
-    fn draw_offer(&self, game_id: GameId) -> SingleValueMapper<Option<ManagedAddress>>;
+    fn draw_offer(&self, game_id: GameId) -> SingleValueMapper<Option<DrawOffer<Self::Api>>>;
+
+    #[view(getLastMove)]
+    #[storage_mapper("lastMove")]
+    fn last_move(&self, game_id: GameId) -> SingleValueMapper<Option<LastMove>>;
+
+    // every move of a game, kept only for games created since it was introduced
+    #[view(getMoveHistory)]
+    #[storage_mapper("moveHistory")]
+    fn move_history(&self, game_id: GameId) -> VecMapper<PlayedMove>;
+
+    #[view(getTakebackRequest)]
+    #[storage_mapper("takebackRequest")]
+    fn takeback_request(&self, game_id: GameId) -> SingleValueMapper<Option<ManagedAddress>>;
+
+    // the most a player can stake on a practice game against the bot
+    #[view(getMaxPracticeWager)]
+    #[storage_mapper("maxPracticeWager")]
+    fn max_practice_wager(&self) -> SingleValueMapper<BigUint>;
+
+    // wager tokens paid to the house, practice stakes and side bet fees
+    #[view(getHousePool)]
+    #[storage_mapper("housePool")]
+    fn house_pool(&self) -> SingleValueMapper<BigUint>;
+
+    // what the player paid to the house for a practice game
+    #[view(getPracticeWager)]
+    #[storage_mapper("practiceWager")]
+    fn practice_wager(&self, game_id: GameId) -> SingleValueMapper<BigUint>;
+
+    // cut of the side pools of a decided game that goes to the house pool, in basis points
+    #[view(getSideBetFee)]
+    #[storage_mapper("sideBetFee")]
+    fn side_bet_fee(&self) -> SingleValueMapper<u32>;
+
+    // everything staked by spectators on one outcome of a game
+    #[view(getSidePool)]
+    #[storage_mapper("sidePool")]
+    fn side_pool(&self, game_id: GameId, outcome: u8) -> SingleValueMapper<BigUint>;
+
+    #[view(getSideBet)]
+    #[storage_mapper("sideBet")]
+    fn side_bet(
+        &self,
+        game_id: GameId,
+        outcome: u8,
+        bettor: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    // what is left of the side pools after the fee, shared by those who backed the outcome;
+    // empty if nobody did or the game was aborted, then every stake is refunded
+    #[view(getSidePayout)]
+    #[storage_mapper("sidePayout")]
+    fn side_payout(&self, game_id: GameId) -> SingleValueMapper<BigUint>;
+
+    // the collection game result tokens are minted in, see `issueResultCollection`
+    #[view(getResultCollection)]
+    #[storage_mapper("resultCollection")]
+    fn result_collection(&self) -> NonFungibleTokenMapper;
+
+    #[view(getWantsResultNft)]
+    #[storage_mapper("wantsResultNft")]
+    fn wants_result_nft(&self, player: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getTournamentOrganizers)]
+    #[storage_mapper("tournamentOrganizers")]
+    fn tournament_organizers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTournaments)]
+    #[storage_mapper("tournaments")]
+    fn tournaments(&self) -> VecMapper<Tournament<Self::Api>>;
+
+    #[view(getTournamentPlayers)]
+    #[storage_mapper("tournamentPlayers")]
+    fn tournament_players(&self, tournament_id: TournamentId)
+        -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTournamentPoints)]
+    #[storage_mapper("tournamentPoints")]
+    fn tournament_points(
+        &self,
+        tournament_id: TournamentId,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("tournamentOpponents")]
+    fn tournament_opponents(
+        &self,
+        tournament_id: TournamentId,
+        player: &ManagedAddress,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("tournamentWhiteGames")]
+    fn tournament_white_games(
+        &self,
+        tournament_id: TournamentId,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("tournamentHadBye")]
+    fn tournament_had_bye(
+        &self,
+        tournament_id: TournamentId,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[view(getTournamentRoundGames)]
+    #[storage_mapper("tournamentRoundGames")]
+    fn tournament_round_games(&self, tournament_id: TournamentId, round: u32) -> VecMapper<GameId>;
+
+    #[view(getGameTournament)]
+    #[storage_mapper("gameTournament")]
+    fn game_tournament(&self, game_id: GameId) -> SingleValueMapper<TournamentId>;
+
+    #[storage_mapper("bracketSlot")]
+    fn bracket_slot(&self, game_id: GameId) -> SingleValueMapper<(u32, usize)>;
+
+    #[view(getBracketWinner)]
+    #[storage_mapper("bracketWinner")]
+    fn bracket_winner(
+        &self,
+        tournament_id: TournamentId,
+        round: u32,
+        position: usize,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(isArmageddon)]
+    #[storage_mapper("isArmageddon")]
+    fn is_armageddon(&self, game_id: GameId) -> SingleValueMapper<bool>;
 }
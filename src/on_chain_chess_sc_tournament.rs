@@ -0,0 +1,578 @@
+use crate::{
+    on_chain_chess_sc_common, on_chain_chess_sc_requirements,
+    on_chain_chess_sc_storage::{self, GameId, Tournament, TournamentId, VARIANT_STANDARD},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+const MAX_PAYOUT_BASIS_POINTS: u32 = 10_000;
+
+#[multiversx_sc::module]
+pub trait OnChainChessScTournament:
+    on_chain_chess_sc_common::OnChainChessScCommon
+    + on_chain_chess_sc_requirements::OnChainChessScRequirements
+    + on_chain_chess_sc_storage::OnChainChessScStorage
+{
+    // pairing_system: 0 for swiss, 1 for round robin, 2 for knockout
+    // draw_rule (knockout only): 0 for an armageddon rematch, 1 for black going through
+    // payout_table: share of the prize pool for each place, in basis points
+    #[endpoint(createTournament)]
+    fn create_tournament(
+        &self,
+        entry_fee: BigUint,
+        rounds: u32,
+        pairing_system: u8,
+        draw_rule: u8,
+        payout_table: MultiValueEncoded<u32>,
+    ) -> TournamentId {
+        self.require_is_active();
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address()
+                || self.tournament_organizers().contains(&caller),
+            "Only the owner or an organizer can create tournaments"
+        );
+        require!(rounds > 0, "A tournament needs at least one round");
+        require!(pairing_system <= 2, "Unknown pairing system");
+        require!(draw_rule <= 1, "Unknown draw rule");
+
+        let payout_table = payout_table.to_vec();
+        let mut total_payout = 0u32;
+        for share in payout_table.iter() {
+            require!(
+                share <= MAX_PAYOUT_BASIS_POINTS,
+                "A payout share cannot exceed 10000 basis points"
+            );
+            total_payout = match total_payout.checked_add(share) {
+                Some(total_payout) => total_payout,
+                None => sc_panic!("Payout table must add up to 10000 basis points"),
+            };
+        }
+        require!(
+            !payout_table.is_empty() && total_payout == MAX_PAYOUT_BASIS_POINTS,
+            "Payout table must add up to 10000 basis points"
+        );
+        require!(
+            pairing_system != 2 || payout_table.len() == 1,
+            "The champion collects the whole prize pool in knockout tournaments"
+        );
+
+        let tournament_id = self.tournaments().len() + 1;
+        self.tournaments().push(&Tournament {
+            tournament_id,
+            organizer: caller,
+            entry_fee,
+            rounds,
+            current_round: 0,
+            pairing_system,
+            draw_rule,
+            payout_table,
+            prize_pool: BigUint::zero(),
+            state: 0,
+        });
+
+        tournament_id
+    }
+
+    #[payable("*")]
+    #[endpoint(registerForTournament)]
+    fn register_for_tournament(&self, tournament_id: TournamentId) {
+        self.require_is_active();
+        self.require_tournament_exists(tournament_id);
+
+        let mut tournament = self.tournaments().get(tournament_id);
+        require!(tournament.state == 0, "Registration is closed");
+
+        // free tournaments are joined without a payment
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(
+            payment.amount == tournament.entry_fee,
+            "Wrong entry fee amount"
+        );
+        if payment.amount != 0 {
+            require!(
+                payment.token_identifier
+                    == EgldOrEsdtTokenIdentifier::esdt(self.wager_token_id().get()),
+                "Wrong entry fee token identifier"
+            );
+        }
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.tournament_players(tournament_id).insert(caller),
+            "You are already registered"
+        );
+
+        tournament.prize_pool += payment.amount;
+        self.tournaments().set(tournament_id, &tournament);
+    }
+
+    // refunds every entry fee; only possible before the first round
+    #[endpoint(cancelTournament)]
+    fn cancel_tournament(&self, tournament_id: TournamentId) {
+        self.require_is_active();
+        self.require_tournament_exists(tournament_id);
+
+        let mut tournament = self.tournaments().get(tournament_id);
+        self.require_tournament_organizer(&tournament);
+        require!(tournament.state == 0, "Tournament already started");
+
+        if tournament.entry_fee > 0 {
+            let wager_token_id = self.wager_token_id().get();
+            for player in self.tournament_players(tournament_id).iter() {
+                self.send()
+                    .direct_esdt(&player, &wager_token_id, 0u64, &tournament.entry_fee);
+            }
+        }
+
+        tournament.prize_pool = BigUint::zero();
+        tournament.state = 3;
+        self.tournaments().set(tournament_id, &tournament);
+    }
+
+    #[endpoint(startTournament)]
+    fn start_tournament(&self, tournament_id: TournamentId) {
+        self.require_is_active();
+        self.require_tournament_exists(tournament_id);
+
+        let mut tournament = self.tournaments().get(tournament_id);
+        self.require_tournament_organizer(&tournament);
+        require!(tournament.state == 0, "Tournament already started");
+
+        let players_count = self.tournament_players(tournament_id).len();
+        require!(players_count >= 2, "Not enough players registered");
+
+        // in round robin everybody meets everybody exactly once
+        if tournament.pairing_system == 1 {
+            tournament.rounds = (players_count + players_count % 2 - 1) as u32;
+        }
+
+        if tournament.pairing_system == 2 {
+            require!(
+                players_count.is_power_of_two(),
+                "Knockout tournaments need a power of two players"
+            );
+            tournament.rounds = players_count.trailing_zeros();
+        }
+
+        tournament.state = 1;
+        tournament.current_round = 1;
+        self.pair_round(&tournament);
+        self.tournaments().set(tournament_id, &tournament);
+    }
+
+    // anyone can move the tournament forward once every game of the round is over
+    #[endpoint(nextRound)]
+    fn next_round(&self, tournament_id: TournamentId) {
+        self.require_is_active();
+        self.require_tournament_exists(tournament_id);
+
+        let mut tournament = self.tournaments().get(tournament_id);
+        require!(tournament.state == 1, "Tournament is not ongoing");
+        require!(
+            tournament.pairing_system != 2,
+            "Knockout tournaments advance automatically"
+        );
+
+        self.record_round_results(tournament_id, tournament.current_round);
+
+        if tournament.current_round == tournament.rounds {
+            self.pay_prizes(&tournament);
+            tournament.prize_pool = BigUint::zero();
+            tournament.state = 2;
+        } else {
+            tournament.current_round += 1;
+            self.pair_round(&tournament);
+        }
+
+        self.tournaments().set(tournament_id, &tournament);
+    }
+
+    // players sorted by points, registration order breaking ties
+    #[view(getTournamentStandings)]
+    fn get_tournament_standings(
+        &self,
+        tournament_id: TournamentId,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let players = self.tournament_players(tournament_id);
+        let mut standings = MultiValueEncoded::new();
+
+        for index in self.sorted_tournament_players(tournament_id).iter() {
+            let player = players.get_by_index(index);
+            let points = self.tournament_points(tournament_id, &player).get();
+            standings.push((player, points).into());
+        }
+
+        standings
+    }
+
+    // indexes in tournament_players, best player first
+    fn sorted_tournament_players(&self, tournament_id: TournamentId) -> ManagedVec<usize> {
+        let players = self.tournament_players(tournament_id);
+
+        let mut points = ManagedVec::<Self::Api, u64>::new();
+        let mut indexes = ManagedVec::<Self::Api, usize>::new();
+        for index in 1..=players.len() {
+            points.push(
+                self.tournament_points(tournament_id, &players.get_by_index(index))
+                    .get(),
+            );
+            indexes.push(index);
+        }
+
+        indexes.sort_unstable_by(|a, b| points.get(*b - 1).cmp(&points.get(*a - 1)).then(a.cmp(b)));
+
+        indexes
+    }
+
+    // called whenever a game ends, tournament or not
+    fn tournament_game_ended(&self, game_id: GameId) {
+        let tournament_id = self.game_tournament(game_id).get();
+        if tournament_id == 0 {
+            return;
+        }
+
+        let mut tournament = self.tournaments().get(tournament_id);
+        if tournament.pairing_system != 2 {
+            return;
+        }
+
+        let game = self.games().get(game_id);
+        let (round, position) = self.bracket_slot(game_id).get();
+
+        let winner = match game.state {
+            1 => game.white_player,
+            2 => game.black_player,
+            _ => {
+                // the rematch is played with reversed colors, and black has draw odds
+                if tournament.draw_rule == 0 && !self.is_armageddon(game_id).get() {
+                    let rematch_id = self.create_bracket_game(
+                        &tournament,
+                        round,
+                        position,
+                        game.black_player,
+                        game.white_player,
+                    );
+                    self.is_armageddon(rematch_id).set(true);
+                    return;
+                }
+
+                game.black_player
+            }
+        };
+
+        self.tournament_points(tournament_id, &winner)
+            .update(|points| *points += 2);
+
+        if round == tournament.rounds {
+            if tournament.prize_pool > 0 {
+                self.send().direct_esdt(
+                    &winner,
+                    &self.wager_token_id().get(),
+                    0u64,
+                    &tournament.prize_pool,
+                );
+            }
+
+            tournament.prize_pool = BigUint::zero();
+            tournament.state = 2;
+            self.tournaments().set(tournament_id, &tournament);
+            return;
+        }
+
+        self.bracket_winner(tournament_id, round, position)
+            .set(&winner);
+
+        let sibling_winner = self.bracket_winner(tournament_id, round, position ^ 1);
+        if sibling_winner.is_empty() {
+            return;
+        }
+
+        // the winner coming from the upper half of the bracket gets white
+        let (white_player, black_player) = if position % 2 == 0 {
+            (winner, sibling_winner.get())
+        } else {
+            (sibling_winner.get(), winner)
+        };
+
+        tournament.current_round = tournament.current_round.max(round + 1);
+        self.create_bracket_game(
+            &tournament,
+            round + 1,
+            position / 2,
+            white_player,
+            black_player,
+        );
+        self.tournaments().set(tournament_id, &tournament);
+    }
+
+    fn pair_round(&self, tournament: &Tournament<Self::Api>) {
+        match tournament.pairing_system {
+            0 => self.pair_swiss_round(tournament),
+            1 => self.pair_round_robin_round(tournament),
+            _ => self.pair_knockout_first_round(tournament),
+        }
+    }
+
+    // top down, everyone gets the best placed opponent they did not meet yet, as long as
+    // the players below can still be paired without rematches; once every pairing would
+    // be a rematch, e.g. two players over two rounds, players meet in standings order
+    fn pair_swiss_round(&self, tournament: &Tournament<Self::Api>) {
+        let tournament_id = tournament.tournament_id;
+        let players = self.tournament_players(tournament_id);
+        let mut standings = self.sorted_tournament_players(tournament_id);
+
+        if standings.len() % 2 == 1 {
+            let mut bye_position = standings.len() - 1;
+            for position in (0..standings.len()).rev() {
+                let player = players.get_by_index(standings.get(position));
+                if !self.tournament_had_bye(tournament_id, &player).get() {
+                    bye_position = position;
+                    break;
+                }
+            }
+
+            let player = players.get_by_index(standings.get(bye_position));
+            self.give_bye(tournament_id, &player);
+            standings.remove(bye_position);
+        }
+
+        let pairing = self
+            .swiss_pairing(tournament_id, &standings)
+            .unwrap_or(standings);
+        for index in (0..pairing.len()).step_by(2) {
+            self.create_tournament_game(
+                tournament,
+                players.get_by_index(pairing.get(index)),
+                players.get_by_index(pairing.get(index + 1)),
+            );
+        }
+    }
+
+    // the pairs one after the other, `None` if some player would have to meet an opponent
+    // again
+    fn swiss_pairing(
+        &self,
+        tournament_id: TournamentId,
+        standings: &ManagedVec<usize>,
+    ) -> Option<ManagedVec<usize>> {
+        if standings.is_empty() {
+            return Some(ManagedVec::new());
+        }
+
+        let players = self.tournament_players(tournament_id);
+        let first = players.get_by_index(standings.get(0));
+        for position in 1..standings.len() {
+            let candidate = players.get_by_index(standings.get(position));
+            if self
+                .tournament_opponents(tournament_id, &first)
+                .contains(&candidate)
+            {
+                continue;
+            }
+
+            let mut rest = standings.clone();
+            rest.remove(position);
+            rest.remove(0);
+            if let Some(rest) = self.swiss_pairing(tournament_id, &rest) {
+                let mut pairing = ManagedVec::new();
+                pairing.push(standings.get(0));
+                pairing.push(standings.get(position));
+                pairing.append_vec(rest);
+                return Some(pairing);
+            }
+        }
+
+        None
+    }
+
+    // circle method; with an odd number of players, whoever meets the missing one has a bye
+    fn pair_round_robin_round(&self, tournament: &Tournament<Self::Api>) {
+        let tournament_id = tournament.tournament_id;
+        let players = self.tournament_players(tournament_id);
+        let players_count = players.len();
+        let circle_size = players_count + players_count % 2;
+        let round = tournament.current_round as usize - 1;
+
+        let position_to_player = |position: usize| -> usize {
+            if position == 0 {
+                1
+            } else {
+                (position - 1 + round) % (circle_size - 1) + 2
+            }
+        };
+
+        for position in 0..circle_size / 2 {
+            let mut first = position_to_player(position);
+            let mut second = position_to_player(circle_size - 1 - position);
+
+            // the fixed player alternates colors every round
+            if (position == 0 && round % 2 == 1) || (position != 0 && position % 2 == 1) {
+                core::mem::swap(&mut first, &mut second);
+            }
+
+            if first > players_count {
+                self.give_bye(tournament_id, &players.get_by_index(second));
+            } else if second > players_count {
+                self.give_bye(tournament_id, &players.get_by_index(first));
+            } else {
+                self.create_tournament_game(
+                    tournament,
+                    players.get_by_index(first),
+                    players.get_by_index(second),
+                );
+            }
+        }
+    }
+
+    // seeds by score, so that the best players can only meet in the late rounds
+    fn pair_knockout_first_round(&self, tournament: &Tournament<Self::Api>) {
+        let tournament_id = tournament.tournament_id;
+        let players = self.tournament_players(tournament_id);
+
+        let mut scores = ManagedVec::<Self::Api, u64>::new();
+        let mut seeds = ManagedVec::<Self::Api, usize>::new();
+        for index in 1..=players.len() {
+            scores.push(self.score(players.get_by_index(index)).get());
+            seeds.push(index);
+        }
+
+        seeds.sort_unstable_by(|a, b| scores.get(*b - 1).cmp(&scores.get(*a - 1)).then(a.cmp(b)));
+
+        // 1 vs 8, 4 vs 5, 2 vs 7, 3 vs 6 and so on
+        let mut bracket = ManagedVec::<Self::Api, usize>::from_single_item(0);
+        while bracket.len() < seeds.len() {
+            let mut next_bracket = ManagedVec::new();
+            let last_seed = bracket.len() * 2 - 1;
+            for seed in bracket.iter() {
+                next_bracket.push(seed);
+                next_bracket.push(last_seed - seed);
+            }
+            bracket = next_bracket;
+        }
+
+        for position in 0..bracket.len() / 2 {
+            self.create_bracket_game(
+                tournament,
+                1,
+                position,
+                players.get_by_index(seeds.get(bracket.get(position * 2))),
+                players.get_by_index(seeds.get(bracket.get(position * 2 + 1))),
+            );
+        }
+    }
+
+    fn create_bracket_game(
+        &self,
+        tournament: &Tournament<Self::Api>,
+        round: u32,
+        position: usize,
+        white_player: ManagedAddress,
+        black_player: ManagedAddress,
+    ) -> GameId {
+        let game_id = self.create_game(white_player, black_player, VARIANT_STANDARD);
+        self.game_tournament(game_id).set(tournament.tournament_id);
+        self.bracket_slot(game_id).set((round, position));
+        self.tournament_round_games(tournament.tournament_id, round)
+            .push(&game_id);
+
+        game_id
+    }
+
+    // the player with fewer white games so far gets white
+    fn create_tournament_game(
+        &self,
+        tournament: &Tournament<Self::Api>,
+        first: ManagedAddress,
+        second: ManagedAddress,
+    ) {
+        let tournament_id = tournament.tournament_id;
+
+        let (white_player, black_player) = if tournament.pairing_system == 0
+            && self.tournament_white_games(tournament_id, &second).get()
+                < self.tournament_white_games(tournament_id, &first).get()
+        {
+            (second, first)
+        } else {
+            (first, second)
+        };
+
+        self.tournament_white_games(tournament_id, &white_player)
+            .update(|white_games| *white_games += 1);
+        self.tournament_opponents(tournament_id, &white_player)
+            .insert(black_player.clone());
+        self.tournament_opponents(tournament_id, &black_player)
+            .insert(white_player.clone());
+
+        let game_id = self.create_game(white_player, black_player, VARIANT_STANDARD);
+        self.game_tournament(game_id).set(tournament_id);
+        self.tournament_round_games(tournament_id, tournament.current_round)
+            .push(&game_id);
+    }
+
+    fn give_bye(&self, tournament_id: TournamentId, player: &ManagedAddress) {
+        self.tournament_had_bye(tournament_id, player).set(true);
+        self.tournament_points(tournament_id, player)
+            .update(|points| *points += 2);
+    }
+
+    // same points as the global score: 2 for a win, 1 for a draw
+    fn record_round_results(&self, tournament_id: TournamentId, round: u32) {
+        for game_id in self.tournament_round_games(tournament_id, round).iter() {
+            let game = self.games().get(game_id);
+
+            match game.state {
+                0 => sc_panic!("Round is not over yet"),
+                1 => self
+                    .tournament_points(tournament_id, &game.white_player)
+                    .update(|points| *points += 2),
+                2 => self
+                    .tournament_points(tournament_id, &game.black_player)
+                    .update(|points| *points += 2),
+                _ => {
+                    self.tournament_points(tournament_id, &game.white_player)
+                        .update(|points| *points += 1);
+                    self.tournament_points(tournament_id, &game.black_player)
+                        .update(|points| *points += 1);
+                }
+            }
+        }
+    }
+
+    // whatever is left after rounding down goes to the winner
+    fn pay_prizes(&self, tournament: &Tournament<Self::Api>) {
+        let tournament_id = tournament.tournament_id;
+        let players = self.tournament_players(tournament_id);
+        let standings = self.sorted_tournament_players(tournament_id);
+        let wager_token_id = self.wager_token_id().get();
+
+        let mut remaining = tournament.prize_pool.clone();
+        let places = tournament.payout_table.len().min(standings.len());
+
+        for place in (1..places).rev() {
+            let prize = &tournament.prize_pool * tournament.payout_table.get(place)
+                / MAX_PAYOUT_BASIS_POINTS;
+            remaining -= &prize;
+
+            if prize > 0 {
+                self.send().direct_esdt(
+                    &players.get_by_index(standings.get(place)),
+                    &wager_token_id,
+                    0u64,
+                    &prize,
+                );
+            }
+        }
+
+        if remaining > 0 {
+            self.send().direct_esdt(
+                &players.get_by_index(standings.get(0)),
+                &wager_token_id,
+                0u64,
+                &remaining,
+            );
+        }
+    }
+}
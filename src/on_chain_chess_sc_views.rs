@@ -0,0 +1,297 @@
+use crate::{
+    on_chain_chess_sc_common, on_chain_chess_sc_requirements,
+    on_chain_chess_sc_storage::{self, DrawOffer, Game, GameId, LastMove},
+};
+use on_chain_chess_core::{
+    check_pseudo_legal, color_of, is_checkmate, is_in_check, is_legal_drop, is_path_blocked,
+    legal_moves, make_piece, play, Bitboards, Move, MoveError, Position, DROP_ORIGIN, PAWN, QUEEN,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+// the most games a listing view returns at once
+pub const MAX_PAGE_SIZE: usize = 100;
+
+// a move the side to move can make, `promotion` is the kind a pawn turns into
+#[type_abi]
+#[derive(
+    NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem, PartialEq, Debug, Clone, Copy,
+)]
+pub struct LegalMove {
+    pub from: u8,
+    pub to: u8,
+    pub promotion: Option<u8>,
+}
+
+// everything needed to show a game in one call
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct GameSnapshot<M: ManagedTypeApi> {
+    pub game: Game<M>,
+    pub board: [u8; 64],
+    pub white_score: u64,
+    pub black_score: u64,
+    pub move_count: u64,
+    pub draw_offer: Option<DrawOffer<M>>,
+    pub takeback_request: Option<ManagedAddress<M>>,
+    pub last_move: Option<LastMove>,
+    pub current_epoch: u64,
+    pub move_deadline_epoch: u64,
+    pub in_check: bool,
+    pub checkmate: bool,
+    pub legal_moves: ManagedVec<M, LegalMove>,
+}
+
+// why `makeMove` would reject a move, or `Valid`
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Debug, Clone, Copy)]
+pub enum MoveValidity {
+    Valid,
+    ContractPaused,
+    GameNotFound,
+    GameNotActive,
+    NotAPlayer,
+    NotYourTurn,
+    TimeExpired,
+    NotOnBoard,
+    NothingMoved,
+    EmptySquare,
+    WrongColor,
+    OwnPieceOnTarget,
+    InvalidPieceMove,
+    BlockedPath,
+    InvalidPromotion,
+    LeavesKingInCheck,
+    MoveNotCommitted,
+}
+
+#[multiversx_sc::module]
+pub trait OnChainChessScViews:
+    on_chain_chess_sc_common::OnChainChessScCommon
+    + on_chain_chess_sc_requirements::OnChainChessScRequirements
+    + on_chain_chess_sc_storage::OnChainChessScStorage
+{
+    #[view(getGameSnapshot)]
+    fn get_game_snapshot(&self, game_id: GameId) -> GameSnapshot<Self::Api> {
+        self.require_game_exists(game_id);
+
+        let game = self.games().get(game_id);
+        let position = self.game_position(&game);
+        let in_check = Bitboards::from_board(&position.board).is_in_check(game.player_turn);
+        // in Bughouse and Crazyhouse a drop can get out of check too
+        let reserve = if self.has_drops(&game) {
+            self.reserve(game_id, game.player_turn).get()
+        } else {
+            [0; 5]
+        };
+        let checkmate = game.state == 0 && is_checkmate(&position, &reserve);
+
+        GameSnapshot {
+            white_score: self.score(game.white_player.clone()).get(),
+            black_score: self.score(game.black_player.clone()).get(),
+            move_count: self.move_count(game_id).get(),
+            draw_offer: self.draw_offer(game_id).get(),
+            takeback_request: self.takeback_request(game_id).get(),
+            last_move: self.last_move(game_id).get(),
+            current_epoch: self.blockchain().get_block_epoch(),
+            // a move is accepted up to one epoch after the previous one
+            move_deadline_epoch: game.last_move_epoch + 1,
+            in_check,
+            checkmate,
+            legal_moves: self.legal_moves_of(&game, &position),
+            board: position.board,
+            game,
+        }
+    }
+
+    // the moves `makeMove` accepts that do not leave the own king in check
+    #[view(getLegalMoves)]
+    fn get_legal_moves(&self, game_id: GameId) -> ManagedVec<LegalMove> {
+        self.require_game_exists(game_id);
+
+        let game = self.games().get(game_id);
+        self.legal_moves_of(&game, &self.game_position(&game))
+    }
+
+    #[view(getLegalMovesFrom)]
+    fn get_legal_moves_from(&self, game_id: GameId, square: u8) -> ManagedVec<LegalMove> {
+        self.require_game_exists(game_id);
+        require!(square < 64, "Square is not on the board");
+
+        let mut moves = ManagedVec::new();
+        for mv in self.get_legal_moves(game_id).iter() {
+            if mv.from == square {
+                moves.push(mv);
+            }
+        }
+        moves
+    }
+
+    // `makeMove` accepts moves that leave the own king in check, this view does not, since
+    // the opponent can call them out right away
+    #[view(validateMove)]
+    fn validate_move(
+        &self,
+        game_id: GameId,
+        player: ManagedAddress,
+        from: u8,
+        to: u8,
+        promotion: OptionalValue<u8>,
+    ) -> MoveValidity {
+        if self.is_paused().get() {
+            return MoveValidity::ContractPaused;
+        }
+        if game_id == 0 || game_id > self.games().len() || self.games().item_is_empty(game_id) {
+            return MoveValidity::GameNotFound;
+        }
+
+        let game = self.games().get(game_id);
+        if game.state != 0 {
+            return MoveValidity::GameNotActive;
+        }
+
+        let player_to_move = if game.player_turn == 0 {
+            &game.white_player
+        } else {
+            &game.black_player
+        };
+        if player != game.white_player && player != game.black_player {
+            return MoveValidity::NotAPlayer;
+        }
+        if &player != player_to_move {
+            return MoveValidity::NotYourTurn;
+        }
+        if self.blockchain().get_block_epoch() > game.last_move_epoch + 1 {
+            return MoveValidity::TimeExpired;
+        }
+        // the first moves of a blind opening go through `commitMove`, as in `makeMove`
+        if self.is_blind_opening(&game) {
+            return MoveValidity::MoveNotCommitted;
+        }
+
+        let position = self.game_position(&game);
+        let mv = self.with_default_promotion(
+            &position,
+            Move {
+                from,
+                to,
+                promotion: promotion.into_option(),
+            },
+        );
+
+        match check_pseudo_legal(&position, mv) {
+            Ok(()) if is_in_check(&play(&position, mv).board, game.player_turn) => {
+                MoveValidity::LeavesKingInCheck
+            }
+            Ok(()) => MoveValidity::Valid,
+            Err(MoveError::NotOnBoard) => MoveValidity::NotOnBoard,
+            Err(MoveError::NothingMoved) => MoveValidity::NothingMoved,
+            Err(MoveError::NotYourPiece) => match color_of(position.board[from as usize]) {
+                None => MoveValidity::EmptySquare,
+                Some(color) if color != game.player_turn => MoveValidity::WrongColor,
+                Some(_) => MoveValidity::OwnPieceOnTarget,
+            },
+            Err(MoveError::InvalidPromotion) => MoveValidity::InvalidPromotion,
+            Err(_) if is_path_blocked(&position.board, mv) => MoveValidity::BlockedPath,
+            Err(_) => MoveValidity::InvalidPieceMove,
+        }
+    }
+
+    // games `offset + 1` to `offset + limit`, in the order they were created
+    #[view(getGamesInRange)]
+    fn get_games_in_range(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<Game<Self::Api>> {
+        self.require_page_size(limit);
+
+        let games = self.games();
+        let mut page = MultiValueEncoded::new();
+        for game_id in (offset + 1)..=games.len().min(offset.saturating_add(limit)) {
+            page.push(games.get(game_id));
+        }
+        page
+    }
+
+    #[view(getGamesByPlayer)]
+    fn get_games_by_player(
+        &self,
+        player: ManagedAddress,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<Game<Self::Api>> {
+        self.require_page_size(limit);
+
+        let game_ids = self.player_games(&player);
+        let mut page = MultiValueEncoded::new();
+        for index in (offset + 1)..=game_ids.len().min(offset.saturating_add(limit)) {
+            page.push(self.games().get(game_ids.get(index)));
+        }
+        page
+    }
+
+    // finished games come in the order they ended; ongoing ones in no particular order, and
+    // as a game ending moves the last ongoing one into its place, paging through them while
+    // games end can skip or repeat one
+    #[view(getGamesByState)]
+    fn get_games_by_state(
+        &self,
+        ongoing: bool,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<Game<Self::Api>> {
+        self.require_page_size(limit);
+
+        let mut page = MultiValueEncoded::new();
+        if ongoing {
+            let game_ids = self.ongoing_games();
+            for index in (offset + 1)..=game_ids.len().min(offset.saturating_add(limit)) {
+                page.push(self.games().get(game_ids.get_by_index(index)));
+            }
+        } else {
+            let game_ids = self.finished_games();
+            for index in (offset + 1)..=game_ids.len().min(offset.saturating_add(limit)) {
+                page.push(self.games().get(game_ids.get(index)));
+            }
+        }
+        page
+    }
+
+    fn require_page_size(&self, limit: usize) {
+        require!(limit <= MAX_PAGE_SIZE, "Page is too large");
+    }
+
+    // empty once the game is over; in games with drops these come from `DROP_ORIGIN` plus
+    // the kind dropped, so a side in check without any legal move is mated there too
+    fn legal_moves_of(&self, game: &Game<Self::Api>, position: &Position) -> ManagedVec<LegalMove> {
+        let mut moves = ManagedVec::new();
+        if game.state != 0 {
+            return moves;
+        }
+
+        for mv in legal_moves(position).iter() {
+            moves.push(LegalMove {
+                from: mv.from,
+                to: mv.to,
+                promotion: mv.promotion,
+            });
+        }
+
+        if self.has_drops(game) {
+            let reserve = self.reserve(game.game_id, game.player_turn).get();
+            for kind in (QUEEN..=PAWN).filter(|&kind| reserve[kind as usize - 1] > 0) {
+                let piece = make_piece(game.player_turn, kind);
+                for square in (0..64).filter(|&square| is_legal_drop(position, piece, square)) {
+                    moves.push(LegalMove {
+                        from: DROP_ORIGIN + kind,
+                        to: square,
+                        promotion: None,
+                    });
+                }
+            }
+        }
+        moves
+    }
+}
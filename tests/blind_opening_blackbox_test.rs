@@ -0,0 +1,183 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::{imports::*, multiversx_chain_vm::crypto_functions::keccak256};
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+fn commitment(mv: &str, salt: &str) -> ManagedByteArray<StaticApi, 32> {
+    let mut preimage = vec![square(&mv[..2]), square(&mv[2..])];
+    preimage.extend_from_slice(salt.as_bytes());
+    ManagedByteArray::new_from_bytes(&keccak256(&preimage))
+}
+
+fn commit(
+    state: &mut ChessTestState,
+    player: TestAddress,
+    mv: &str,
+    salt: &str,
+    error: Option<&str>,
+) {
+    let tx = state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .commit_move(1usize, commitment(mv, salt));
+
+    run_tx!(tx, error);
+}
+
+fn reveal(
+    state: &mut ChessTestState,
+    player: TestAddress,
+    mv: &str,
+    salt: &str,
+    error: Option<&str>,
+) {
+    let tx = state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .reveal_move(1usize, square(&mv[..2]), square(&mv[2..]), salt);
+
+    run_tx!(tx, error);
+}
+
+#[test]
+fn first_moves_are_played_once_both_are_revealed() {
+    let mut state = ChessTestState::new_with_variant_game(BLIND_OPENING);
+    state.make_move_fails(
+        WHITE,
+        "e2",
+        "e4",
+        "Moves of the blind opening are committed first",
+    );
+
+    commit(&mut state, BLACK, "c7c5", "black salt", None);
+    commit(&mut state, WHITE, "e2e4", "white salt", None);
+
+    reveal(&mut state, BLACK, "c7c5", "black salt", None);
+    assert_eq!(state.piece_at("c5"), EMPTY);
+    reveal(&mut state, WHITE, "e2e4", "white salt", None);
+
+    assert_eq!(state.piece_at("e4"), WHITE_PAWN);
+    assert_eq!(state.piece_at("c5"), BLACK_PAWN);
+    assert_eq!(state.game(1).player_turn, 0);
+    assert_eq!(state.snapshot().move_count, 2);
+
+    // from here on the game goes on as usual
+    state.play(&["g1f3", "d7d6"]);
+    assert_eq!(state.piece_at("d6"), BLACK_PAWN);
+}
+
+#[test]
+fn reveals_must_match_the_commitments() {
+    let mut state = ChessTestState::new_with_variant_game(BLIND_OPENING);
+    commit(
+        &mut state,
+        STRANGER,
+        "e2e4",
+        "salt",
+        Some("You are not part of the game"),
+    );
+
+    commit(&mut state, WHITE, "e2e5", "white salt", None);
+    commit(
+        &mut state,
+        WHITE,
+        "e2e4",
+        "white salt",
+        Some("You already committed a move"),
+    );
+    reveal(
+        &mut state,
+        WHITE,
+        "e2e5",
+        "white salt",
+        Some("Both players have to commit first"),
+    );
+
+    commit(&mut state, BLACK, "e7e5", "black salt", None);
+    reveal(
+        &mut state,
+        BLACK,
+        "e7e5",
+        "other salt",
+        Some("Move does not match the commitment"),
+    );
+    reveal(
+        &mut state,
+        BLACK,
+        "d7d5",
+        "black salt",
+        Some("Move does not match the commitment"),
+    );
+    reveal(&mut state, BLACK, "e7e5", "black salt", None);
+    reveal(
+        &mut state,
+        BLACK,
+        "e7e5",
+        "black salt",
+        Some("You already revealed your move"),
+    );
+
+    // a move committed to is still checked when it is revealed
+    reveal(
+        &mut state,
+        WHITE,
+        "e2e5",
+        "white salt",
+        Some("Invalid pawn move"),
+    );
+}
+
+#[test]
+fn blind_moves_must_be_revealed_in_time() {
+    let mut state = ChessTestState::new_with_variant_game(BLIND_OPENING);
+    commit(&mut state, WHITE, "d2d4", "white salt", None);
+    commit(&mut state, BLACK, "g8f6", "black salt", None);
+
+    state.set_epoch(1);
+    reveal(&mut state, WHITE, "d2d4", "white salt", None);
+    state.call_fails(
+        WHITE,
+        GameEndpoint::SignalInactivity,
+        "Opponent can still make a move",
+    );
+
+    state.set_epoch(3);
+    reveal(
+        &mut state,
+        BLACK,
+        "g8f6",
+        "black salt",
+        Some("You took too long to make a move"),
+    );
+    state.call_fails(BLACK, GameEndpoint::SignalInactivity, "You are up to move");
+    state.call(WHITE, GameEndpoint::SignalInactivity);
+
+    state.check_game_state(WHITE_WON);
+    state.check_balance(WHITE, INITIAL_BALANCE + WAGER);
+}
+
+#[test]
+fn blind_moves_must_be_committed_in_time() {
+    let mut state = ChessTestState::new_with_variant_game(BLIND_OPENING);
+    commit(&mut state, BLACK, "e7e5", "black salt", None);
+    state.call_fails(WHITE, GameEndpoint::SignalInactivity, "You are up to move");
+
+    state.set_epoch(2);
+    commit(
+        &mut state,
+        WHITE,
+        "e2e4",
+        "white salt",
+        Some("You took too long to make a move"),
+    );
+    state.call(BLACK, GameEndpoint::SignalInactivity);
+
+    state.check_game_state(BLACK_WON);
+}
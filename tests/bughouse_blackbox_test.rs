@@ -0,0 +1,245 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+// game 1 is `WHITE` against `BLACK`, game 2 `STRANGER` against `PARTNER`, so `WHITE` and
+// `PARTNER` play together against `BLACK` and `STRANGER`
+fn bughouse_match() -> ChessTestState {
+    let mut state = ChessTestState::new_active();
+    for player in [WHITE, BLACK, STRANGER, PARTNER] {
+        state.join_game_with_variant(player, BUGHOUSE);
+    }
+    state
+}
+
+fn move_on(state: &mut ChessTestState, game_id: usize, player: TestAddress, mv: &str) {
+    state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .make_move(game_id, square(&mv[..2]), square(&mv[2..]))
+        .run();
+}
+
+fn drop_on(
+    state: &mut ChessTestState,
+    game_id: usize,
+    player: TestAddress,
+    piece: u8,
+    name: &str,
+    error: Option<&str>,
+) {
+    let tx = state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .drop_piece(game_id, piece, square(name));
+
+    run_tx!(tx, error);
+}
+
+fn reserve(state: &mut ChessTestState, game_id: usize, color: u8) -> [u8; 5] {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .reserve(game_id, color)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn four_players_start_two_linked_games() {
+    let mut state = ChessTestState::new_active();
+    for player in [WHITE, BLACK, STRANGER] {
+        state.join_game_with_variant(player, BUGHOUSE);
+    }
+    state
+        .world
+        .tx()
+        .from(BLACK)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .join_game(OptionalValue::Some(BUGHOUSE))
+        .single_esdt(&WAGER_TOKEN.into(), 0, &WAGER.into())
+        .returns(ExpectError(4, "You cannot play against yourself"))
+        .run();
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .bughouse_queue()
+        .returns(ExpectValue(MultiValueVec::from(vec![
+            WHITE.to_managed_address(),
+            BLACK.to_managed_address(),
+            STRANGER.to_managed_address(),
+        ])))
+        .run();
+
+    state.join_game_with_variant(PARTNER, BUGHOUSE);
+    let first = state.game(1);
+    let second = state.game(2);
+    assert_eq!(first.white_player, WHITE.to_managed_address());
+    assert_eq!(first.black_player, BLACK.to_managed_address());
+    assert_eq!(second.white_player, STRANGER.to_managed_address());
+    assert_eq!(second.black_player, PARTNER.to_managed_address());
+    assert_eq!((first.variant, second.variant), (BUGHOUSE, BUGHOUSE));
+
+    for (game_id, linked) in [(1usize, 2usize), (2, 1)] {
+        state
+            .world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .linked_game(game_id)
+            .returns(ExpectValue(linked))
+            .run();
+    }
+}
+
+#[test]
+fn captures_go_to_the_partner() {
+    let mut state = bughouse_match();
+
+    // white promotes on b8 and black takes the new queen back
+    for (player, mv) in [
+        (WHITE, "b2b4"),
+        (BLACK, "h7h6"),
+        (WHITE, "b4b5"),
+        (BLACK, "h6h5"),
+        (WHITE, "b5b6"),
+        (BLACK, "h5h4"),
+        (WHITE, "b6a7"),
+        (BLACK, "h4h3"),
+        (WHITE, "a7b8"),
+        (BLACK, "a8b8"),
+    ] {
+        move_on(&mut state, 1, player, mv);
+    }
+
+    // the partner of black plays white on the other board, and the queen is a pawn again
+    assert_eq!(reserve(&mut state, 2, 1), [0, 0, 0, 1, 1]);
+    assert_eq!(reserve(&mut state, 2, 0), [0, 0, 0, 0, 1]);
+    assert_eq!(reserve(&mut state, 1, 0), [0, 0, 0, 0, 0]);
+
+    drop_on(
+        &mut state,
+        2,
+        STRANGER,
+        WHITE_KNIGHT,
+        "e4",
+        Some("You have no such piece to drop"),
+    );
+    drop_on(
+        &mut state,
+        2,
+        STRANGER,
+        WHITE_PAWN,
+        "e2",
+        Some("Square is not empty"),
+    );
+    drop_on(
+        &mut state,
+        2,
+        STRANGER,
+        BLACK_PAWN,
+        "e4",
+        Some("Invalid move"),
+    );
+    drop_on(&mut state, 2, STRANGER, WHITE_PAWN, "e4", None);
+    assert_eq!(reserve(&mut state, 2, 0), [0, 0, 0, 0, 0]);
+
+    drop_on(&mut state, 2, PARTNER, BLACK_KNIGHT, "e5", None);
+    assert_eq!(reserve(&mut state, 2, 1), [0, 0, 0, 0, 1]);
+
+    let board: [u8; 64] = state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .get_board(2usize)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(board[square("e4") as usize], WHITE_PAWN);
+    assert_eq!(board[square("e5") as usize], BLACK_KNIGHT);
+    assert_eq!(state.game(2).player_turn, 0);
+
+    // drops are recorded as coming from `64` plus the kind
+    let history: Vec<(u8, u8)> = state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .move_history(2usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|mv| (mv.from, mv.to))
+        .collect();
+    assert_eq!(history, [(69, square("e4")), (68, square("e5"))]);
+}
+
+#[test]
+fn teams_win_and_lose_together() {
+    let mut state = bughouse_match();
+    move_on(&mut state, 1, WHITE, "e2e4");
+    move_on(&mut state, 2, STRANGER, "d2d4");
+
+    state.call(WHITE, GameEndpoint::Resign);
+
+    state.check_game_state(BLACK_WON);
+    assert_eq!(state.game(2).state, WHITE_WON);
+    state.check_balance(BLACK, INITIAL_BALANCE + WAGER);
+    state.check_balance(STRANGER, INITIAL_BALANCE + WAGER);
+    state.check_balance(WHITE, INITIAL_BALANCE - WAGER);
+    state.check_balance(PARTNER, INITIAL_BALANCE - WAGER);
+    state.check_score(BLACK, 2);
+    state.check_score(STRANGER, 2);
+}
+
+#[test]
+fn linked_games_are_aborted_together() {
+    let mut state = bughouse_match();
+    move_on(&mut state, 2, STRANGER, "d2d4");
+    move_on(&mut state, 2, PARTNER, "d7d5");
+    state.call_fails(
+        WHITE,
+        GameEndpoint::AbortGame,
+        "Game can no longer be aborted",
+    );
+
+    let mut state = bughouse_match();
+    state.call(WHITE, GameEndpoint::AbortGame);
+    assert_eq!(state.game(2).state, ABORTED);
+    state.check_balance(PARTNER, INITIAL_BALANCE);
+}
+
+#[test]
+fn only_bughouse_games_have_drops() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "d7d5", "e4d5"]);
+    drop_on(
+        &mut state,
+        1,
+        BLACK,
+        BLACK_PAWN,
+        "e5",
+        Some("Pieces cannot be dropped in this game"),
+    );
+
+    let mut state = bughouse_match();
+    move_on(&mut state, 1, WHITE, "e2e4");
+    state.call_fails(
+        WHITE,
+        GameEndpoint::RequestTakeback,
+        "Moves cannot be taken back in this game",
+    );
+}
@@ -0,0 +1,402 @@
+#![allow(dead_code)]
+
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy::{self, Game, GameSnapshot};
+
+pub const CODE_PATH: MxscPath = MxscPath::new("output/on-chain-chess-sc.mxsc.json");
+
+pub const OWNER: TestAddress = TestAddress::new("owner");
+pub const WHITE: TestAddress = TestAddress::new("white");
+pub const BLACK: TestAddress = TestAddress::new("black");
+pub const STRANGER: TestAddress = TestAddress::new("stranger");
+pub const PARTNER: TestAddress = TestAddress::new("partner");
+pub const CHESS_ADDRESS: TestSCAddress = TestSCAddress::new("chess");
+
+pub const WAGER_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("CHESS-123456");
+pub const OTHER_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("OTHER-123456");
+pub const WAGER: u64 = 100;
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+pub const WHITE_KING: u8 = 0;
+pub const WHITE_QUEEN: u8 = 1;
+pub const WHITE_ROOK: u8 = 2;
+pub const WHITE_BISHOP: u8 = 3;
+pub const WHITE_KNIGHT: u8 = 4;
+pub const WHITE_PAWN: u8 = 5;
+pub const EMPTY: u8 = 6;
+pub const BLACK_KING: u8 = 7;
+pub const BLACK_QUEEN: u8 = 8;
+pub const BLACK_KNIGHT: u8 = 11;
+pub const BLACK_PAWN: u8 = 12;
+
+pub const ONGOING: u8 = 0;
+pub const WHITE_WON: u8 = 1;
+pub const BLACK_WON: u8 = 2;
+pub const DRAW: u8 = 3;
+pub const ABORTED: u8 = 4;
+
+pub const STANDARD: u8 = 0;
+pub const CHESS960: u8 = 1;
+pub const KING_OF_THE_HILL: u8 = 2;
+pub const THREE_CHECK: u8 = 3;
+pub const BLIND_OPENING: u8 = 4;
+pub const BUGHOUSE: u8 = 5;
+pub const CRAZYHOUSE: u8 = 6;
+
+/// Turns a square such as `e2` into the contract's index, where `0` is a8 and `63` is h1.
+pub fn square(name: &str) -> u8 {
+    let bytes = name.as_bytes();
+    (bytes[0] - b'a') + (b'8' - bytes[1]) * 8
+}
+
+pub fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.register_contract(CODE_PATH, on_chain_chess_sc::ContractBuilder);
+    world
+}
+
+/// Runs `tx`, expecting it to fail with the message in `error` if there is one.
+macro_rules! run_tx {
+    ($tx:expr, $error:expr) => {
+        match $error {
+            Some(message) => {
+                $tx.returns(ExpectError(4, message)).run();
+            }
+            None => {
+                $tx.run();
+            }
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use run_tx;
+
+pub struct ChessTestState {
+    pub world: ScenarioWorld,
+}
+
+impl ChessTestState {
+    /// Deploys the contract, which starts paused and without a wager.
+    pub fn new() -> Self {
+        let mut world = world();
+
+        // the owner collects the house pool of practice games
+        world
+            .account(OWNER)
+            .nonce(1)
+            .esdt_balance(WAGER_TOKEN, INITIAL_BALANCE);
+        for player in [WHITE, BLACK, STRANGER, PARTNER] {
+            world
+                .account(player)
+                .nonce(1)
+                .esdt_balance(WAGER_TOKEN, INITIAL_BALANCE)
+                .esdt_balance(OTHER_TOKEN, INITIAL_BALANCE);
+        }
+
+        world
+            .tx()
+            .from(OWNER)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .init()
+            .code(CODE_PATH)
+            .new_address(CHESS_ADDRESS)
+            .run();
+
+        Self { world }
+    }
+
+    /// Deploys the contract, sets the wager and opens it for players.
+    pub fn new_active() -> Self {
+        let mut state = Self::new();
+        state.set_wager();
+        state.unpause();
+        state
+    }
+
+    /// Same as `new_active`, with game 1 started between `WHITE` and `BLACK`.
+    pub fn new_with_game() -> Self {
+        let mut state = Self::new_active();
+        state.join_game(WHITE);
+        state.join_game(BLACK);
+        state
+    }
+
+    pub fn set_wager(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .set_wager(WAGER_TOKEN, WAGER)
+            .run();
+    }
+
+    pub fn pause(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .pause()
+            .run();
+    }
+
+    pub fn unpause(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .unpause()
+            .run();
+    }
+
+    pub fn set_epoch(&mut self, epoch: u64) {
+        self.world.current_block().block_epoch(epoch);
+    }
+
+    pub fn join_game(&mut self, player: TestAddress) {
+        self.world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .join_game(OptionalValue::<u8>::None)
+            .single_esdt(&WAGER_TOKEN.into(), 0, &WAGER.into())
+            .run();
+    }
+
+    pub fn join_game_with_variant(&mut self, player: TestAddress, variant: u8) {
+        self.world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .join_game(OptionalValue::Some(variant))
+            .single_esdt(&WAGER_TOKEN.into(), 0, &WAGER.into())
+            .run();
+    }
+
+    /// Same as `new_with_game`, in the given variant.
+    pub fn new_with_variant_game(variant: u8) -> Self {
+        let mut state = Self::new_active();
+        state.join_game_with_variant(WHITE, variant);
+        state.join_game_with_variant(BLACK, variant);
+        state
+    }
+
+    /// Fills the block random seed with `byte`, which decides the next Chess960 position.
+    pub fn set_random_seed(&mut self, byte: u8) {
+        self.world
+            .current_block()
+            .block_random_seed(ManagedBuffer::<StaticApi>::from(&[byte; 48][..]));
+    }
+
+    pub fn join_game_fails(
+        &mut self,
+        player: TestAddress,
+        token: TestTokenIdentifier,
+        amount: u64,
+        message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .join_game(OptionalValue::<u8>::None)
+            .single_esdt(&token.into(), 0, &amount.into())
+            .returns(ExpectError(4, message))
+            .run();
+    }
+
+    /// Plays `e2e4`-like moves in game 1, alternating between white and black.
+    pub fn play(&mut self, moves: &[&str]) {
+        for mv in moves {
+            let player = self.player_to_move(1);
+            self.make_move(player, &mv[..2], &mv[2..]);
+        }
+    }
+
+    pub fn make_move(&mut self, player: TestAddress, from: &str, to: &str) {
+        self.world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .make_move(1usize, square(from), square(to))
+            .run();
+    }
+
+    pub fn make_move_fails(&mut self, player: TestAddress, from: &str, to: &str, message: &str) {
+        self.world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .make_move(1usize, square(from), square(to))
+            .returns(ExpectError(4, message))
+            .run();
+    }
+
+    /// Tries the next move of the side to move and expects it to be rejected.
+    pub fn illegal_move(&mut self, mv: &str, message: &str) {
+        let player = self.player_to_move(1);
+        self.make_move_fails(player, &mv[..2], &mv[2..], message);
+    }
+
+    /// Sends `notation` to `makeMoveUci` or `makeMoveSan`, expecting `error` if given.
+    pub fn move_in_notation(&mut self, notation: Notation, error: Option<&str>) {
+        let player = self.player_to_move(1);
+        let tx = self
+            .world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy);
+
+        let tx = match notation {
+            Notation::Uci(mv) => tx.make_move_uci(1usize, ManagedBuffer::from(mv)),
+            Notation::San(mv) => tx.make_move_san(1usize, ManagedBuffer::from(mv)),
+        };
+
+        run_tx!(tx, error);
+    }
+
+    pub fn game(&mut self, game_id: usize) -> Game<StaticApi> {
+        self.world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .games()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .nth(game_id - 1)
+            .expect("game does not exist")
+    }
+
+    pub fn player_to_move(&mut self, game_id: usize) -> TestAddress<'static> {
+        if self.game(game_id).player_turn == 0 {
+            WHITE
+        } else {
+            BLACK
+        }
+    }
+
+    pub fn board(&mut self) -> [u8; 64] {
+        self.world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .get_board(1usize)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+    }
+
+    pub fn snapshot(&mut self) -> GameSnapshot<StaticApi> {
+        self.world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .get_game_snapshot(1usize)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn castling_rights(&mut self) -> u8 {
+        self.world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .castling_rights(1usize)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+    }
+
+    pub fn piece_at(&mut self, name: &str) -> u8 {
+        self.board()[square(name) as usize]
+    }
+
+    pub fn check_game_state(&mut self, state: u8) {
+        assert_eq!(self.game(1).state, state);
+    }
+
+    pub fn check_score(&mut self, player: TestAddress, score: u64) {
+        self.world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .score(player)
+            .returns(ExpectValue(score))
+            .run();
+    }
+
+    pub fn check_balance(&mut self, player: TestAddress, balance: u64) {
+        self.world
+            .check_account(player)
+            .esdt_balance(WAGER_TOKEN, balance);
+    }
+
+    /// The wager tokens the house matches practice stakes with.
+    pub fn house_pool(&mut self) -> u64 {
+        self.world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .house_pool()
+            .returns(ReturnsResult)
+            .run()
+            .to_u64()
+            .unwrap()
+    }
+
+    pub fn call(&mut self, player: TestAddress, endpoint: GameEndpoint) {
+        self.call_endpoint(player, endpoint, None);
+    }
+
+    pub fn call_fails(&mut self, player: TestAddress, endpoint: GameEndpoint, message: &str) {
+        self.call_endpoint(player, endpoint, Some(message));
+    }
+
+    fn call_endpoint(&mut self, player: TestAddress, endpoint: GameEndpoint, error: Option<&str>) {
+        let tx = self
+            .world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy);
+
+        let tx = match endpoint {
+            GameEndpoint::Draw => tx.draw(1usize),
+            GameEndpoint::WithdrawDrawOffer => tx.withdraw_draw_offer(1usize),
+            GameEndpoint::CallIllegalMove => tx.call_illegal_move(1usize),
+            GameEndpoint::SignalInactivity => tx.signal_inactivity(1usize),
+            GameEndpoint::Resign => tx.resign(1usize),
+            GameEndpoint::AbortGame => tx.abort_game(1usize),
+            GameEndpoint::RequestTakeback => tx.request_takeback(1usize),
+            GameEndpoint::AcceptTakeback => tx.accept_takeback(1usize),
+        };
+
+        run_tx!(tx, error);
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Notation {
+    Uci(&'static str),
+    San(&'static str),
+}
+
+/// The endpoints taking nothing but the game id.
+#[derive(Clone, Copy)]
+pub enum GameEndpoint {
+    Draw,
+    WithdrawDrawOffer,
+    CallIllegalMove,
+    SignalInactivity,
+    Resign,
+    AbortGame,
+    RequestTakeback,
+    AcceptTakeback,
+}
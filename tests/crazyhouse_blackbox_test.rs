@@ -0,0 +1,117 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+// drops show up among the legal moves as coming from `64` plus the kind
+const PAWN_DROP: u8 = 69;
+
+fn drop_piece(state: &mut ChessTestState, piece: u8, name: &str, error: Option<&str>) {
+    let player = state.player_to_move(1);
+    let tx = state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .drop_piece(1usize, piece, square(name));
+
+    run_tx!(tx, error);
+}
+
+fn pocket(state: &mut ChessTestState, color: u8) -> [u8; 5] {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .reserve(1usize, color)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn captured_pieces_change_sides() {
+    let mut state = ChessTestState::new_with_variant_game(CRAZYHOUSE);
+    assert_eq!(state.game(1).variant, CRAZYHOUSE);
+
+    state.play(&["e2e4", "d7d5", "e4d5", "d8d5", "b1c3"]);
+    assert_eq!(pocket(&mut state, 0), [0, 0, 0, 0, 1]);
+    assert_eq!(pocket(&mut state, 1), [0, 0, 0, 0, 1]);
+
+    // the queen takes the knight, which joins black's pocket
+    state.play(&["d5a5", "g1f3", "a5c3", "d2c3"]);
+    assert_eq!(pocket(&mut state, 1), [0, 0, 0, 1, 1]);
+    assert_eq!(pocket(&mut state, 0), [1, 0, 0, 0, 1]);
+
+    drop_piece(&mut state, BLACK_KNIGHT, "e4", None);
+    assert_eq!(state.piece_at("e4"), BLACK_KNIGHT);
+    assert_eq!(pocket(&mut state, 1), [0, 0, 0, 0, 1]);
+
+    drop_piece(&mut state, WHITE_QUEEN, "e4", Some("Square is not empty"));
+    drop_piece(
+        &mut state,
+        WHITE_KNIGHT,
+        "e5",
+        Some("You have no such piece to drop"),
+    );
+    drop_piece(&mut state, WHITE_QUEEN, "d5", None);
+    assert_eq!(state.piece_at("d5"), WHITE_QUEEN);
+    assert_eq!(pocket(&mut state, 0), [0, 0, 0, 0, 1]);
+}
+
+#[test]
+fn drops_count_as_replies_to_a_check() {
+    let mut state = ChessTestState::new_with_variant_game(CRAZYHOUSE);
+    state.play(&["e2e4", "d7d5", "e4d5", "d8d5", "g1f3", "d5e5"]);
+
+    let snapshot = state.snapshot();
+    assert!(snapshot.in_check);
+
+    // a pawn can only block the check, on e4, e3 or e2
+    let drops: Vec<(u8, u8)> = snapshot
+        .legal_moves
+        .iter()
+        .filter(|mv| mv.from >= 64)
+        .map(|mv| (mv.from, mv.to))
+        .collect();
+    assert_eq!(
+        drops,
+        [
+            (PAWN_DROP, square("e4")),
+            (PAWN_DROP, square("e3")),
+            (PAWN_DROP, square("e2")),
+        ]
+    );
+
+    drop_piece(&mut state, WHITE_PAWN, "e3", None);
+    assert!(!state.snapshot().in_check);
+}
+
+#[test]
+fn a_drop_can_save_a_mated_king() {
+    let mut state = ChessTestState::new_with_variant_game(CRAZYHOUSE);
+    state.play(&[
+        "b1c3", "d7d5", "c3d5", "e7e5", "f2f3", "a7a6", "g2g4", "d8h4",
+    ]);
+
+    // no move gets the king out, but the pawn white took can block on g3 or f2
+    let snapshot = state.snapshot();
+    assert!(snapshot.in_check);
+    assert!(!snapshot.checkmate);
+    assert!(snapshot.legal_moves.iter().all(|mv| mv.from == PAWN_DROP));
+
+    drop_piece(&mut state, WHITE_PAWN, "g3", None);
+    assert!(!state.snapshot().in_check);
+}
+
+#[test]
+fn an_empty_pocket_cannot_save_a_mated_king() {
+    let mut state = ChessTestState::new_with_variant_game(CRAZYHOUSE);
+    state.play(&["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+    let snapshot = state.snapshot();
+    assert!(snapshot.checkmate);
+    assert!(snapshot.legal_moves.is_empty());
+}
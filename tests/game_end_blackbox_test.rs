@@ -0,0 +1,224 @@
+mod chess_setup;
+
+use chess_setup::*;
+
+#[test]
+fn call_illegal_move_after_queen_check() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["f2f3", "e7e5", "e1f2", "d8h4", "a2a3"]);
+
+    state.call_fails(WHITE, GameEndpoint::CallIllegalMove, "You just moved");
+    state.call(BLACK, GameEndpoint::CallIllegalMove);
+
+    state.check_game_state(BLACK_WON);
+    state.check_balance(WHITE, INITIAL_BALANCE - WAGER);
+    state.check_balance(BLACK, INITIAL_BALANCE + WAGER);
+    state.check_score(BLACK, 2);
+    state.check_score(WHITE, 0);
+}
+
+#[test]
+fn call_illegal_move_after_knight_check() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "b8c6", "a2a3", "c6d4", "a3a4", "d4f3", "a4a5"]);
+
+    state.call(BLACK, GameEndpoint::CallIllegalMove);
+    state.check_game_state(BLACK_WON);
+}
+
+#[test]
+fn call_illegal_move_after_pawn_check() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "e7e5", "d2d4", "e8e7", "d4d5", "e7e6"]);
+
+    state.call(WHITE, GameEndpoint::CallIllegalMove);
+    state.check_game_state(WHITE_WON);
+    state.check_balance(WHITE, INITIAL_BALANCE + WAGER);
+}
+
+#[test]
+fn call_illegal_move_after_rook_file_check() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "d7d5", "e4d5", "d8d5", "a2a3", "d5e5", "a3a4"]);
+
+    state.call(BLACK, GameEndpoint::CallIllegalMove);
+    state.check_game_state(BLACK_WON);
+}
+
+#[test]
+fn call_illegal_move_without_check_fails() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "e7e5"]);
+
+    state.call_fails(WHITE, GameEndpoint::CallIllegalMove, "King is not in check");
+    state.call_fails(
+        STRANGER,
+        GameEndpoint::CallIllegalMove,
+        "You are not part of the game",
+    );
+    state.check_game_state(ONGOING);
+}
+
+#[test]
+fn draw_offer_is_accepted_by_the_opponent() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4"]);
+
+    state.call(WHITE, GameEndpoint::Draw);
+    // repeating the offer changes nothing
+    state.call(WHITE, GameEndpoint::Draw);
+    state.check_game_state(ONGOING);
+
+    state.call(BLACK, GameEndpoint::Draw);
+    state.check_game_state(DRAW);
+    state.check_balance(WHITE, INITIAL_BALANCE);
+    state.check_balance(BLACK, INITIAL_BALANCE);
+    state.check_score(WHITE, 1);
+    state.check_score(BLACK, 1);
+
+    state.make_move_fails(BLACK, "e7", "e5", "Game is not active");
+}
+
+#[test]
+fn draw_offer_is_declined_by_playing_on() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4"]);
+
+    state.call(WHITE, GameEndpoint::Draw);
+    state.play(&["e7e5"]);
+
+    // the offer is gone, so this is a new offer from black
+    state.call(BLACK, GameEndpoint::Draw);
+    state.check_game_state(ONGOING);
+    state.call(WHITE, GameEndpoint::Draw);
+    state.check_game_state(DRAW);
+}
+
+#[test]
+fn draw_offer_survives_own_move() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.call(WHITE, GameEndpoint::Draw);
+    state.play(&["e2e4"]);
+    state.call(BLACK, GameEndpoint::Draw);
+    state.check_game_state(DRAW);
+}
+
+#[test]
+fn draw_offer_can_be_withdrawn() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.call_fails(
+        WHITE,
+        GameEndpoint::WithdrawDrawOffer,
+        "You have no draw offer to withdraw",
+    );
+    state.call(WHITE, GameEndpoint::Draw);
+    state.call_fails(
+        BLACK,
+        GameEndpoint::WithdrawDrawOffer,
+        "You have no draw offer to withdraw",
+    );
+    state.call(WHITE, GameEndpoint::WithdrawDrawOffer);
+
+    state.call(BLACK, GameEndpoint::Draw);
+    state.check_game_state(ONGOING);
+}
+
+#[test]
+fn resign() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.call_fails(
+        STRANGER,
+        GameEndpoint::Resign,
+        "You are not part of the game",
+    );
+    state.call(WHITE, GameEndpoint::Resign);
+
+    state.check_game_state(BLACK_WON);
+    state.check_balance(BLACK, INITIAL_BALANCE + WAGER);
+    state.call_fails(BLACK, GameEndpoint::Resign, "Game is not active");
+}
+
+#[test]
+fn signal_inactivity() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4"]);
+
+    state.call_fails(BLACK, GameEndpoint::SignalInactivity, "You are up to move");
+    state.call_fails(
+        WHITE,
+        GameEndpoint::SignalInactivity,
+        "Opponent can still make a move",
+    );
+
+    state.set_epoch(1);
+    state.call_fails(
+        WHITE,
+        GameEndpoint::SignalInactivity,
+        "Opponent can still make a move",
+    );
+
+    state.set_epoch(2);
+    state.make_move_fails(BLACK, "e7", "e5", "You took too long to make a move");
+    state.call(WHITE, GameEndpoint::SignalInactivity);
+
+    state.check_game_state(WHITE_WON);
+    state.check_balance(WHITE, INITIAL_BALANCE + WAGER);
+}
+
+#[test]
+fn moves_reset_the_inactivity_clock() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.set_epoch(1);
+    state.play(&["e2e4"]);
+    state.set_epoch(2);
+    state.play(&["e7e5"]);
+    state.set_epoch(3);
+    state.call_fails(
+        BLACK,
+        GameEndpoint::SignalInactivity,
+        "Opponent can still make a move",
+    );
+    state.play(&["g1f3"]);
+}
+
+#[test]
+fn abort_game() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4"]);
+
+    state.call(BLACK, GameEndpoint::AbortGame);
+    state.check_game_state(ABORTED);
+    state.check_balance(WHITE, INITIAL_BALANCE);
+    state.check_balance(BLACK, INITIAL_BALANCE);
+    state.check_score(WHITE, 0);
+    state.check_score(BLACK, 0);
+}
+
+#[test]
+fn abort_game_after_both_moved_fails() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "e7e5"]);
+
+    state.call_fails(
+        WHITE,
+        GameEndpoint::AbortGame,
+        "Game can no longer be aborted",
+    );
+}
+
+#[test]
+fn game_endpoints_are_blocked_while_paused() {
+    let mut state = ChessTestState::new_with_game();
+    state.pause();
+
+    state.make_move_fails(WHITE, "e2", "e4", "Contract is paused");
+    state.call_fails(WHITE, GameEndpoint::Resign, "Contract is paused");
+    state.call_fails(WHITE, GameEndpoint::Draw, "Contract is paused");
+
+    state.unpause();
+    state.play(&["e2e4"]);
+}
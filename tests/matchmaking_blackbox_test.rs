@@ -0,0 +1,213 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+#[test]
+fn first_player_waits_for_an_opponent() {
+    let mut state = ChessTestState::new_active();
+    state.join_game(WHITE);
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .is_player_waiting_opt()
+        .returns(ExpectValue(Some(ManagedAddress::from(
+            WHITE.eval_to_array(),
+        ))))
+        .run();
+
+    state.check_balance(WHITE, INITIAL_BALANCE - WAGER);
+}
+
+#[test]
+fn second_player_starts_a_game() {
+    let mut state = ChessTestState::new_with_game();
+
+    let game = state.game(1);
+    assert_eq!(game.game_id, 1);
+    assert_eq!(game.white_player, WHITE.to_managed_address());
+    assert_eq!(game.black_player, BLACK.to_managed_address());
+    assert_eq!(game.player_turn, 0);
+    assert_eq!(game.state, ONGOING);
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .is_player_waiting_opt()
+        .returns(ExpectValue(None::<ManagedAddress<StaticApi>>))
+        .run();
+
+    assert_eq!(state.piece_at("e1"), WHITE_KING);
+    assert_eq!(state.piece_at("d8"), BLACK_QUEEN);
+    assert_eq!(state.piece_at("e4"), EMPTY);
+    state.check_balance(BLACK, INITIAL_BALANCE - WAGER);
+}
+
+#[test]
+fn third_player_waits_for_the_next_game() {
+    let mut state = ChessTestState::new_with_game();
+    state.join_game(STRANGER);
+    state.join_game(WHITE);
+
+    let game = state.game(2);
+    assert_eq!(game.white_player, STRANGER.to_managed_address());
+    assert_eq!(game.black_player, WHITE.to_managed_address());
+}
+
+#[test]
+fn cannot_play_against_yourself() {
+    let mut state = ChessTestState::new_active();
+    state.join_game(WHITE);
+    state.join_game_fails(
+        WHITE,
+        WAGER_TOKEN,
+        WAGER,
+        "You cannot play against yourself",
+    );
+}
+
+#[test]
+fn wager_must_match() {
+    let mut state = ChessTestState::new_active();
+    state.join_game_fails(WHITE, WAGER_TOKEN, WAGER - 1, "Wrong wager amount");
+    state.join_game_fails(WHITE, OTHER_TOKEN, WAGER, "Wrong wager token identifier");
+}
+
+#[test]
+fn wager_can_only_be_set_once() {
+    let mut state = ChessTestState::new_active();
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .set_wager(OTHER_TOKEN, WAGER * 2)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .wager_amount()
+        .returns(ExpectValue(WAGER))
+        .run();
+    state.join_game(WHITE);
+}
+
+#[test]
+fn contract_starts_paused() {
+    let mut state = ChessTestState::new();
+    state.set_wager();
+    state.join_game_fails(WHITE, WAGER_TOKEN, WAGER, "Contract is paused");
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .is_paused()
+        .returns(ExpectValue(true))
+        .run();
+}
+
+#[test]
+fn only_the_owner_can_pause() {
+    let mut state = ChessTestState::new_active();
+
+    state
+        .world
+        .tx()
+        .from(STRANGER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .pause()
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn pause_blocks_every_player_endpoint() {
+    let mut state = ChessTestState::new_with_game();
+    state.pause();
+
+    state.join_game_fails(STRANGER, WAGER_TOKEN, WAGER, "Contract is paused");
+    state.make_move_fails(WHITE, "e2", "e4", "Contract is paused");
+    for endpoint in [
+        GameEndpoint::Draw,
+        GameEndpoint::WithdrawDrawOffer,
+        GameEndpoint::CallIllegalMove,
+        GameEndpoint::SignalInactivity,
+        GameEndpoint::Resign,
+        GameEndpoint::AbortGame,
+        GameEndpoint::RequestTakeback,
+        GameEndpoint::AcceptTakeback,
+    ] {
+        state.call_fails(WHITE, endpoint, "Contract is paused");
+    }
+
+    state.unpause();
+    state.make_move(WHITE, "e2", "e4");
+}
+
+#[test]
+fn players_are_matched_by_variant() {
+    let mut state = ChessTestState::new_active();
+    state.join_game_with_variant(WHITE, CHESS960);
+    state.join_game(BLACK);
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .waiting_player(CHESS960)
+        .returns(ExpectValue(Some(ManagedAddress::from(
+            WHITE.eval_to_array(),
+        ))))
+        .run();
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .is_player_waiting_opt()
+        .returns(ExpectValue(Some(ManagedAddress::from(
+            BLACK.eval_to_array(),
+        ))))
+        .run();
+
+    state.join_game_with_variant(STRANGER, CHESS960);
+    let game = state.game(1);
+    assert_eq!(game.white_player, WHITE.to_managed_address());
+    assert_eq!(game.black_player, STRANGER.to_managed_address());
+    assert_eq!(game.variant, CHESS960);
+
+    state.join_game_with_variant(WHITE, STANDARD);
+    assert_eq!(state.game(2).black_player, WHITE.to_managed_address());
+    assert_eq!(state.game(2).variant, STANDARD);
+}
+
+#[test]
+fn variant_must_be_known() {
+    let mut state = ChessTestState::new_active();
+
+    state
+        .world
+        .tx()
+        .from(WHITE)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .join_game(OptionalValue::Some(9u8))
+        .single_esdt(&WAGER_TOKEN.into(), 0, &WAGER.into())
+        .returns(ExpectError(4, "Unknown variant"))
+        .run();
+}
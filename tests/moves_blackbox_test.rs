@@ -0,0 +1,188 @@
+mod chess_setup;
+
+use chess_setup::*;
+
+#[test]
+fn pawn_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.illegal_move("e2e5", "Invalid pawn move");
+    state.illegal_move("e2d3", "Invalid pawn move");
+    state.play(&["e2e4", "e7e5"]);
+    assert_eq!(state.piece_at("e4"), WHITE_PAWN);
+    assert_eq!(state.piece_at("e2"), EMPTY);
+
+    // blocked by the black pawn
+    state.illegal_move("e4e5", "Invalid pawn move");
+    state.play(&["d2d3", "d7d5"]);
+    state.illegal_move("d3d2", "Invalid pawn move");
+    state.illegal_move("d3d5", "Invalid pawn move");
+
+    state.play(&["e4d5", "a7a6"]);
+    assert_eq!(state.piece_at("d5"), WHITE_PAWN);
+    assert_eq!(state.piece_at("e4"), EMPTY);
+
+    // pawns move at most two squares
+    state.illegal_move("b2b5", "Invalid pawn move");
+    state.play(&["b2b4", "a6a5"]);
+    state.play(&["h2h3", "a5b4"]);
+    assert_eq!(state.piece_at("b4"), BLACK_PAWN);
+}
+
+#[test]
+fn pawn_jump_over_a_piece() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["g1f3", "b8c6"]);
+    state.illegal_move("f2f4", "Invalid pawn move");
+    state.play(&["e2e4"]);
+    state.illegal_move("c7c5", "Invalid pawn move");
+}
+
+#[test]
+fn pawn_promotes_to_a_queen() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&[
+        "h2h4", "g7g5", "h4g5", "h7h6", "g5h6", "b8c6", "h6h7", "c6d4", "h7g8",
+    ]);
+
+    assert_eq!(state.piece_at("g8"), WHITE_QUEEN);
+    assert_eq!(state.piece_at("h7"), EMPTY);
+}
+
+#[test]
+fn black_pawn_promotes_to_a_black_queen() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&[
+        "a2a4", "h7h5", "a4a5", "h5h4", "a5a6", "h4h3", "a6b7", "h3g2", "b7a8", "g2h1",
+    ]);
+
+    assert_eq!(state.piece_at("a8"), WHITE_QUEEN);
+    assert_eq!(state.piece_at("h1"), BLACK_QUEEN);
+    assert_eq!(state.piece_at("g2"), EMPTY);
+}
+
+#[test]
+fn knight_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.illegal_move("g1g3", "Invalid knight move");
+    state.illegal_move("g1e2", "Invalid move");
+    state.play(&["g1f3", "b8c6", "f3e5", "c6e5"]);
+    assert_eq!(state.piece_at("e5"), BLACK_KNIGHT);
+    state.illegal_move("b1b2", "Invalid move");
+    state.illegal_move("b1d3", "Invalid knight move");
+}
+
+#[test]
+fn bishop_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.illegal_move("f1c4", "Invalid bishop move");
+    // the path goes through d2, not through e2
+    state.play(&["e2e4", "e7e5"]);
+    state.illegal_move("c1f4", "Invalid bishop move");
+    state.play(&["f1c4", "d7d6"]);
+    state.illegal_move("c4c5", "Invalid bishop move");
+    state.play(&["c4f7"]);
+    state.illegal_move("c8a6", "Invalid bishop move");
+    state.play(&["e8f7"]);
+    assert_eq!(state.piece_at("f7"), BLACK_KING);
+}
+
+#[test]
+fn rook_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.illegal_move("a1a3", "Invalid rook move");
+    state.play(&["a2a4", "h7h5", "a1a3", "h8h6", "a3h3"]);
+    state.illegal_move("h6g5", "Invalid rook move");
+    state.illegal_move("h6h2", "Invalid rook move");
+    state.illegal_move("h6h3", "Invalid rook move");
+    state.play(&["h6c6"]);
+}
+
+#[test]
+fn queen_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.illegal_move("d1d3", "Invalid queen move");
+    state.play(&["e2e4", "e7e5"]);
+    state.illegal_move("d1f4", "Invalid queen move");
+    state.play(&["d1h5", "b8c6", "h5f7"]);
+}
+
+#[test]
+fn king_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.play(&["e2e4", "e7e5"]);
+    state.illegal_move("e1e3", "Invalid king move");
+    state.play(&["e1e2", "e8e7", "e2d3"]);
+}
+
+#[test]
+fn moves_must_be_on_the_board_and_of_your_own_pieces() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.make_move_fails(
+        BLACK,
+        "e7",
+        "e5",
+        "It is not your turn or you are not part of the game",
+    );
+    state.make_move_fails(
+        STRANGER,
+        "e2",
+        "e4",
+        "It is not your turn or you are not part of the game",
+    );
+    state.illegal_move("e2e2", "You did not move anything");
+    state.illegal_move("e7e5", "Invalid move");
+    state.illegal_move("d1e1", "Invalid move");
+    state.illegal_move("e3e4", "Invalid move");
+}
+
+#[test]
+fn takeback_reverts_the_last_move() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "d7d5", "e4d5"]);
+
+    state.call_fails(
+        BLACK,
+        GameEndpoint::RequestTakeback,
+        "You can only take back your own move",
+    );
+    state.call_fails(
+        BLACK,
+        GameEndpoint::AcceptTakeback,
+        "There is no takeback request from your opponent",
+    );
+    state.call(WHITE, GameEndpoint::RequestTakeback);
+    state.call_fails(
+        WHITE,
+        GameEndpoint::AcceptTakeback,
+        "There is no takeback request from your opponent",
+    );
+    state.call(BLACK, GameEndpoint::AcceptTakeback);
+
+    assert_eq!(state.piece_at("e4"), WHITE_PAWN);
+    assert_eq!(state.piece_at("d5"), BLACK_PAWN);
+    assert_eq!(state.player_to_move(1), WHITE);
+
+    // only one move can be taken back
+    state.call_fails(
+        WHITE,
+        GameEndpoint::RequestTakeback,
+        "You can only take back your own move",
+    );
+    state.play(&["e4e5"]);
+    state.call(WHITE, GameEndpoint::RequestTakeback);
+
+    // playing on declines the request
+    state.play(&["d5d4"]);
+    state.call_fails(
+        WHITE,
+        GameEndpoint::AcceptTakeback,
+        "There is no takeback request from your opponent",
+    );
+}
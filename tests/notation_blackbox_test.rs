@@ -0,0 +1,74 @@
+mod chess_setup;
+
+use chess_setup::*;
+use Notation::{San, Uci};
+
+#[test]
+fn uci_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.move_in_notation(Uci("e2e4"), None);
+    state.move_in_notation(Uci("e7e5"), None);
+    state.move_in_notation(Uci("g1f3"), None);
+    assert_eq!(state.piece_at("e4"), WHITE_PAWN);
+    assert_eq!(state.piece_at("e5"), BLACK_PAWN);
+    assert_eq!(state.piece_at("f3"), WHITE_KNIGHT);
+
+    // parsed moves go through the same checks as makeMove
+    state.move_in_notation(Uci("e5e3"), Some("Invalid pawn move"));
+    state.move_in_notation(Uci("e5"), Some("Invalid move notation"));
+    state.move_in_notation(Uci("e7e9"), Some("Invalid move notation"));
+    state.move_in_notation(Uci("e7e6k"), Some("Invalid move notation"));
+    state.move_in_notation(Uci("e7e6qqqqqqqqqqqqqqq"), Some("Invalid move notation"));
+    state.move_in_notation(Uci("d7d6q"), Some("Invalid promotion"));
+}
+
+#[test]
+fn uci_promotion() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&[
+        "h2h4", "g7g5", "h4g5", "h7h6", "g5h6", "b8c6", "h6h7", "c6d4",
+    ]);
+
+    state.move_in_notation(Uci("h7g8n"), None);
+    assert_eq!(state.piece_at("g8"), WHITE_KNIGHT);
+
+    state.play(&["d4c2"]);
+    state.move_in_notation(Uci("g8f6"), None);
+    assert_eq!(state.piece_at("f6"), WHITE_KNIGHT);
+}
+
+#[test]
+fn san_moves() {
+    let mut state = ChessTestState::new_with_game();
+
+    state.move_in_notation(San("e4"), None);
+    state.move_in_notation(San("e5"), None);
+    state.move_in_notation(San("Nf3"), None);
+    state.move_in_notation(San("Nc6"), None);
+    state.move_in_notation(San("Bb5"), None);
+    state.move_in_notation(San("d6"), None);
+    state.move_in_notation(San("Bxc6+"), None);
+    assert_eq!(state.piece_at("c6"), WHITE_BISHOP);
+
+    state.move_in_notation(San("bxc6"), None);
+    assert_eq!(state.piece_at("c6"), BLACK_PAWN);
+    assert_eq!(state.piece_at("b7"), EMPTY);
+
+    state.move_in_notation(San("Nd5"), Some("No piece can make this move"));
+    state.move_in_notation(San("Nc3"), None);
+    state.move_in_notation(San("Zz9"), Some("Invalid move notation"));
+    state.move_in_notation(San("Ke2"), Some("No piece can make this move"));
+}
+
+#[test]
+fn san_ambiguous_moves() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["g1f3", "a7a6", "b1c3", "a6a5", "c3e4", "a5a4"]);
+
+    // both knights can reach g5
+    state.move_in_notation(San("Ng5"), Some("Ambiguous move"));
+    state.move_in_notation(San("Neg5"), None);
+    assert_eq!(state.piece_at("g5"), WHITE_KNIGHT);
+    assert_eq!(state.piece_at("f3"), WHITE_KNIGHT);
+}
@@ -0,0 +1,101 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    ScenarioWorld::vm_go()
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn call_illegal_move_go() {
+    world().run("scenarios/call_illegal_move.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn call_illegal_move_no_check_go() {
+    world().run("scenarios/call_illegal_move_no_check.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn draw_go() {
+    world().run("scenarios/draw.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn init_go() {
+    world().run("scenarios/init.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn join_game_go() {
+    world().run("scenarios/join_game.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn make_move_go() {
+    world().run("scenarios/make_move.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn paused_go() {
+    world().run("scenarios/paused.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn practice_go() {
+    world().run("scenarios/practice.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn resign_go() {
+    world().run("scenarios/resign.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn side_bets_go() {
+    world().run("scenarios/side_bets.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn signal_inactivity_go() {
+    world().run("scenarios/signal_inactivity.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn tournament_go() {
+    world().run("scenarios/tournament.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn upgrade_go() {
+    world().run("scenarios/upgrade.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn variants_go() {
+    world().run("scenarios/variants.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and a wasm build"]
+fn board_gas_go() {
+    world().run("scenarios/gas/board_gas.scen.json");
+}
+
+#[test]
+#[ignore = "needs mx-scenario-go and the wasm of scenarios/gas/build_baseline.sh"]
+fn board_gas_baseline_go() {
+    world().run("scenarios/gas/board_gas_baseline.scen.json");
+}
@@ -0,0 +1,88 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(
+        "mxsc:output/on-chain-chess-sc.mxsc.json",
+        on_chain_chess_sc::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn call_illegal_move_rs() {
+    world().run("scenarios/call_illegal_move.scen.json");
+}
+
+#[test]
+fn call_illegal_move_no_check_rs() {
+    world().run("scenarios/call_illegal_move_no_check.scen.json");
+}
+
+#[test]
+fn draw_rs() {
+    world().run("scenarios/draw.scen.json");
+}
+
+#[test]
+fn init_rs() {
+    world().run("scenarios/init.scen.json");
+}
+
+#[test]
+fn join_game_rs() {
+    world().run("scenarios/join_game.scen.json");
+}
+
+#[test]
+fn make_move_rs() {
+    world().run("scenarios/make_move.scen.json");
+}
+
+#[test]
+fn paused_rs() {
+    world().run("scenarios/paused.scen.json");
+}
+
+#[test]
+fn practice_rs() {
+    world().run("scenarios/practice.scen.json");
+}
+
+#[test]
+fn resign_rs() {
+    world().run("scenarios/resign.scen.json");
+}
+
+#[test]
+fn side_bets_rs() {
+    world().run("scenarios/side_bets.scen.json");
+}
+
+#[test]
+fn signal_inactivity_rs() {
+    world().run("scenarios/signal_inactivity.scen.json");
+}
+
+#[test]
+fn tournament_rs() {
+    world().run("scenarios/tournament.scen.json");
+}
+
+#[test]
+fn upgrade_rs() {
+    world().run("scenarios/upgrade.scen.json");
+}
+
+// the gas is only charged by the Go VM, this checks that the moves go through
+
+#[test]
+fn variants_rs() {
+    world().run("scenarios/variants.scen.json");
+}
+
+#[test]
+fn board_gas_rs() {
+    world().run("scenarios/gas/board_gas.scen.json");
+}
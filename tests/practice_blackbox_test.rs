@@ -0,0 +1,167 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+const MAX_PRACTICE_WAGER: u64 = 50;
+
+fn with_practice_limit() -> ChessTestState {
+    let mut state = ChessTestState::new_active();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .set_max_practice_wager(MAX_PRACTICE_WAGER)
+        .run();
+    state
+}
+
+fn start_practice_game(state: &mut ChessTestState, stake: u64, error: Option<&str>) {
+    let (token, stake) = (WAGER_TOKEN.into(), stake.into());
+    let tx = state
+        .world
+        .tx()
+        .from(WHITE)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .start_practice_game()
+        .single_esdt(&token, 0, &stake);
+
+    run_tx!(tx, error);
+}
+
+#[test]
+fn the_bot_answers_every_move() {
+    let mut state = ChessTestState::new_active();
+    state
+        .world
+        .tx()
+        .from(WHITE)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .start_practice_game()
+        .run();
+
+    let game = state.game(1);
+    assert_eq!(game.white_player, WHITE.to_managed_address());
+    assert_eq!(game.black_player, CHESS_ADDRESS.to_managed_address());
+
+    // with nothing to take, the bot plays the first move it finds
+    state.make_move(WHITE, "e2", "e4");
+    assert_eq!(state.piece_at("a6"), BLACK_KNIGHT);
+    assert_eq!(state.game(1).player_turn, 0);
+    assert_eq!(state.snapshot().move_count, 2);
+
+    // otherwise it takes the most it can
+    state.make_move(WHITE, "f1", "a6");
+    assert_eq!(state.piece_at("a6"), BLACK_PAWN);
+    assert_eq!(state.game(1).player_turn, 0);
+}
+
+#[test]
+fn practice_stakes_go_to_the_house() {
+    let mut state = with_practice_limit();
+    start_practice_game(
+        &mut state,
+        MAX_PRACTICE_WAGER + 1,
+        Some("Practice wager is too high"),
+    );
+
+    start_practice_game(&mut state, MAX_PRACTICE_WAGER, None);
+    assert_eq!(state.house_pool(), MAX_PRACTICE_WAGER);
+    state.make_move(WHITE, "e2", "e4");
+    state.call(WHITE, GameEndpoint::Resign);
+
+    assert_eq!(state.house_pool(), MAX_PRACTICE_WAGER);
+    state.check_balance(WHITE, INITIAL_BALANCE - MAX_PRACTICE_WAGER);
+    state.check_score(WHITE, 0);
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .score(CHESS_ADDRESS)
+        .returns(ExpectValue(0u64))
+        .run();
+}
+
+#[test]
+fn beating_the_bot_pays_nothing() {
+    let mut state = with_practice_limit();
+    start_practice_game(&mut state, MAX_PRACTICE_WAGER, None);
+
+    // the bot answers the same way every time, so this mate can be replayed at will
+    state.make_move(WHITE, "e2", "e3");
+    state.make_move(WHITE, "d1", "h5");
+    state.make_move(WHITE, "f1", "c4");
+    state.make_move(WHITE, "h5", "f7");
+
+    state.check_game_state(WHITE_WON);
+    assert_eq!(state.house_pool(), MAX_PRACTICE_WAGER);
+    state.check_balance(WHITE, INITIAL_BALANCE - MAX_PRACTICE_WAGER);
+    state.check_score(WHITE, 0);
+}
+
+#[test]
+fn aborted_practice_games_keep_the_stake() {
+    let mut state = with_practice_limit();
+    start_practice_game(&mut state, 30, None);
+    state.call(WHITE, GameEndpoint::AbortGame);
+
+    state.check_game_state(ABORTED);
+    assert_eq!(state.house_pool(), 30);
+    state.check_balance(WHITE, INITIAL_BALANCE - 30);
+
+    // the owner can take out what the house collected
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .withdraw_house_pool(31u64)
+        .returns(ExpectError(4, "Not enough tokens in the house pool"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .withdraw_house_pool(30u64)
+        .run();
+    assert_eq!(state.house_pool(), 0);
+    state
+        .world
+        .check_account(OWNER)
+        .esdt_balance(WAGER_TOKEN, INITIAL_BALANCE + 30);
+}
+
+#[test]
+fn abandoned_practice_games_go_to_the_house() {
+    let mut state = with_practice_limit();
+    start_practice_game(&mut state, 30, None);
+    state.make_move(WHITE, "e2", "e4");
+
+    state.set_epoch(1);
+    state.call_fails(
+        OWNER,
+        GameEndpoint::SignalInactivity,
+        "Opponent can still make a move",
+    );
+    state.set_epoch(2);
+    state.call_fails(
+        STRANGER,
+        GameEndpoint::SignalInactivity,
+        "You are not part of the game",
+    );
+    state.call_fails(WHITE, GameEndpoint::SignalInactivity, "You are up to move");
+    state.call(OWNER, GameEndpoint::SignalInactivity);
+
+    state.check_game_state(BLACK_WON);
+    assert_eq!(state.house_pool(), 30);
+    state.check_balance(WHITE, INITIAL_BALANCE - 30);
+}
@@ -0,0 +1,131 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_core::pack_board;
+use on_chain_chess_sc::{on_chain_chess_sc_proxy, on_chain_chess_sc_result_nfts::GameResult};
+
+fn with_result_collection() -> ChessTestState {
+    let mut state = ChessTestState::new_with_game();
+    // the collection is issued asynchronously, the VM only runs calls to accounts with code
+    // but handles the system SC by itself
+    state
+        .world
+        .account(ESDTSystemSCAddress.to_managed_address::<StaticApi>())
+        .code(CODE_PATH);
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .issue_result_collection("ChessResults", "CHESSRES")
+        .run();
+    state
+}
+
+fn result_collection(state: &mut ChessTestState) -> TokenIdentifier<StaticApi> {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .result_collection()
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn opt_in(state: &mut ChessTestState, player: TestAddress, wants: bool) {
+    state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .set_wants_result_nft(wants)
+        .run();
+}
+
+fn game_result(state: &mut ChessTestState, outcome: u8, move_count: u64) -> GameResult<StaticApi> {
+    GameResult {
+        game_id: 1,
+        white_player: WHITE.to_managed_address(),
+        black_player: BLACK.to_managed_address(),
+        outcome,
+        move_count,
+        board: pack_board(&state.board()),
+    }
+}
+
+#[test]
+fn players_who_opted_in_get_the_result() {
+    let mut state = with_result_collection();
+    let token = result_collection(&mut state);
+    assert!(token
+        .as_managed_buffer()
+        .to_boxed_bytes()
+        .as_slice()
+        .starts_with(b"CHESSRES-"));
+
+    opt_in(&mut state, WHITE, true);
+    opt_in(&mut state, BLACK, true);
+    opt_in(&mut state, BLACK, false);
+    state.play(&["f2f3", "e7e5", "g2g4", "d8h4"]);
+    state.call(WHITE, GameEndpoint::Resign);
+
+    let attributes = game_result(&mut state, BLACK_WON, 4);
+    state
+        .world
+        .check_account(WHITE)
+        .esdt_nft_balance_and_attributes(&token, 1, 1, attributes);
+    state.world.check_account(BLACK).esdt_balance(&token, 0);
+}
+
+#[test]
+fn both_players_share_a_nonce() {
+    let mut state = with_result_collection();
+    let token = result_collection(&mut state);
+    opt_in(&mut state, WHITE, true);
+    opt_in(&mut state, BLACK, true);
+
+    state.make_move(WHITE, "e2", "e4");
+    state.call(WHITE, GameEndpoint::Draw);
+    state.call(BLACK, GameEndpoint::Draw);
+
+    let attributes = game_result(&mut state, DRAW, 1);
+    for player in [WHITE, BLACK] {
+        state
+            .world
+            .check_account(player)
+            .esdt_nft_balance_and_attributes(&token, 1, 1, &attributes);
+    }
+}
+
+#[test]
+fn aborted_games_have_no_result() {
+    let mut state = with_result_collection();
+    let token = result_collection(&mut state);
+    opt_in(&mut state, WHITE, true);
+
+    state.call(WHITE, GameEndpoint::AbortGame);
+    state.world.check_account(WHITE).esdt_balance(&token, 0);
+}
+
+#[test]
+fn only_the_owner_issues_the_collection() {
+    let mut state = ChessTestState::new_with_game();
+    state
+        .world
+        .tx()
+        .from(WHITE)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .issue_result_collection("ChessResults", "CHESSRES")
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    // without a collection, games end as usual
+    opt_in(&mut state, WHITE, true);
+    state.call(BLACK, GameEndpoint::Resign);
+    state.check_game_state(WHITE_WON);
+}
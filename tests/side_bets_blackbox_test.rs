@@ -0,0 +1,135 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+fn set_fee(state: &mut ChessTestState, fee: u32) {
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .set_side_bet_fee(fee)
+        .run();
+}
+
+fn bet(
+    state: &mut ChessTestState,
+    bettor: TestAddress,
+    outcome: u8,
+    amount: u64,
+    error: Option<&str>,
+) {
+    let (token, amount) = (WAGER_TOKEN.into(), amount.into());
+    let tx = state
+        .world
+        .tx()
+        .from(bettor)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .place_side_bet(1usize, outcome)
+        .single_esdt(&token, 0, &amount);
+
+    run_tx!(tx, error);
+}
+
+fn claim(state: &mut ChessTestState, bettor: TestAddress, error: Option<&str>) {
+    let tx = state
+        .world
+        .tx()
+        .from(bettor)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .claim_side_bet(1usize);
+
+    run_tx!(tx, error);
+}
+
+#[test]
+fn backers_of_the_outcome_share_the_pools() {
+    let mut state = ChessTestState::new_with_game();
+    set_fee(&mut state, 500);
+
+    bet(&mut state, STRANGER, WHITE_WON, 30, None);
+    bet(&mut state, OWNER, WHITE_WON, 10, None);
+    bet(&mut state, PARTNER, BLACK_WON, 50, None);
+    bet(&mut state, PARTNER, DRAW, 10, None);
+    claim(&mut state, STRANGER, Some("Game is still ongoing"));
+
+    state.call(BLACK, GameEndpoint::Resign);
+
+    // 5 of the 100 staked go to the house, white's backers share the other 95
+    assert_eq!(state.house_pool(), 5);
+    claim(&mut state, STRANGER, None);
+    claim(&mut state, OWNER, None);
+    claim(&mut state, STRANGER, Some("Nothing to claim"));
+    claim(&mut state, PARTNER, Some("Nothing to claim"));
+
+    state.check_balance(STRANGER, INITIAL_BALANCE - 30 + 71);
+    state.check_balance(PARTNER, INITIAL_BALANCE - 60);
+    state
+        .world
+        .check_account(OWNER)
+        .esdt_balance(WAGER_TOKEN, INITIAL_BALANCE - 10 + 23);
+}
+
+#[test]
+fn stakes_are_refunded_without_backers_of_the_outcome() {
+    let mut state = ChessTestState::new_with_game();
+    set_fee(&mut state, 500);
+
+    bet(&mut state, STRANGER, BLACK_WON, 30, None);
+    bet(&mut state, STRANGER, DRAW, 20, None);
+    state.call(BLACK, GameEndpoint::Resign);
+
+    claim(&mut state, STRANGER, None);
+    state.check_balance(STRANGER, INITIAL_BALANCE);
+    assert_eq!(state.house_pool(), 0);
+
+    let mut state = ChessTestState::new_with_game();
+    bet(&mut state, STRANGER, WHITE_WON, 30, None);
+    state.call(WHITE, GameEndpoint::AbortGame);
+
+    claim(&mut state, STRANGER, None);
+    state.check_balance(STRANGER, INITIAL_BALANCE);
+}
+
+#[test]
+fn side_bets_are_limited_to_spectators_and_the_opening() {
+    let mut state = ChessTestState::new_with_game();
+    bet(
+        &mut state,
+        WHITE,
+        BLACK_WON,
+        10,
+        Some("Players cannot bet on their own games"),
+    );
+    bet(
+        &mut state,
+        BLACK,
+        BLACK_WON,
+        10,
+        Some("Players cannot bet on their own games"),
+    );
+    bet(&mut state, STRANGER, ONGOING, 10, Some("Unknown outcome"));
+    bet(&mut state, STRANGER, ABORTED, 10, Some("Unknown outcome"));
+
+    state.play(&[
+        "e2e4", "e7e5", "g1f3", "b8c6", "f1c4", "g8f6", "d2d3", "f8c5", "c2c3",
+    ]);
+    bet(&mut state, STRANGER, DRAW, 10, None);
+    state.play(&["d7d6"]);
+    bet(&mut state, STRANGER, DRAW, 10, Some("Side bets are closed"));
+
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .set_side_bet_fee(10_000u32)
+        .returns(ExpectError(4, "Fee must be below 10000 basis points"))
+        .run();
+}
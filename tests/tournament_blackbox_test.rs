@@ -0,0 +1,613 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+const ENTRY_FEE: u64 = 100;
+
+const SWISS: u8 = 0;
+const ROUND_ROBIN: u8 = 1;
+const KNOCKOUT: u8 = 2;
+
+const ARMAGEDDON: u8 = 0;
+const BLACK_GOES_THROUGH: u8 = 1;
+
+const FIFTH: TestAddress = TestAddress::new("fifth");
+const SIXTH: TestAddress = TestAddress::new("sixth");
+
+fn create_tournament(
+    state: &mut ChessTestState,
+    organizer: TestAddress,
+    rounds: u32,
+    pairing_system: u8,
+    draw_rule: u8,
+    payout_table: &[u32],
+    error: Option<&str>,
+) {
+    let tx = state
+        .world
+        .tx()
+        .from(organizer)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .create_tournament(
+            ENTRY_FEE,
+            rounds,
+            pairing_system,
+            draw_rule,
+            MultiValueVec::from(payout_table.to_vec()),
+        );
+
+    run_tx!(tx, error);
+}
+
+fn register(state: &mut ChessTestState, players: &[TestAddress]) {
+    for &player in players {
+        pay_entry_fee(state, player, WAGER_TOKEN, ENTRY_FEE, None);
+    }
+}
+
+fn pay_entry_fee(
+    state: &mut ChessTestState,
+    player: TestAddress,
+    token: TestTokenIdentifier,
+    amount: u64,
+    error: Option<&str>,
+) {
+    let (token, amount) = (token.into(), amount.into());
+    let tx = state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .register_for_tournament(1usize)
+        .single_esdt(&token, 0, &amount);
+
+    run_tx!(tx, error);
+}
+
+fn start_tournament(state: &mut ChessTestState) {
+    manage(state, OWNER, TournamentEndpoint::Start, None);
+}
+
+fn next_round(state: &mut ChessTestState) {
+    // anyone can move the tournament on
+    manage(state, STRANGER, TournamentEndpoint::NextRound, None);
+}
+
+enum TournamentEndpoint {
+    Start,
+    NextRound,
+    Cancel,
+}
+
+fn manage(
+    state: &mut ChessTestState,
+    caller: TestAddress,
+    endpoint: TournamentEndpoint,
+    error: Option<&str>,
+) {
+    let tx = state
+        .world
+        .tx()
+        .from(caller)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy);
+    let tx = match endpoint {
+        TournamentEndpoint::Start => tx.start_tournament(1usize),
+        TournamentEndpoint::NextRound => tx.next_round(1usize),
+        TournamentEndpoint::Cancel => tx.cancel_tournament(1usize),
+    };
+
+    run_tx!(tx, error);
+}
+
+fn set_organizer(
+    state: &mut ChessTestState,
+    caller: TestAddress,
+    organizer: TestAddress,
+    add: bool,
+) {
+    let tx = state
+        .world
+        .tx()
+        .from(caller)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy);
+    let tx = if add {
+        tx.add_tournament_organizer(organizer)
+    } else {
+        tx.remove_tournament_organizer(organizer)
+    };
+
+    if caller == OWNER {
+        tx.run();
+    } else {
+        tx.returns(ExpectError(4, "Endpoint can only be called by owner"))
+            .run();
+    }
+}
+
+fn resign(state: &mut ChessTestState, player: TestAddress, game_id: usize) {
+    state
+        .world
+        .tx()
+        .from(player)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .resign(game_id)
+        .run();
+}
+
+fn draw(state: &mut ChessTestState, game_id: usize) {
+    let game = state.game(game_id);
+    for player in [game.white_player, game.black_player] {
+        state
+            .world
+            .tx()
+            .from(&player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .draw(game_id)
+            .run();
+    }
+}
+
+fn round_games(state: &mut ChessTestState, round: u32) -> Vec<usize> {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .tournament_round_games(1usize, round)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect()
+}
+
+fn points(state: &mut ChessTestState, player: TestAddress) -> u64 {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .tournament_points(1usize, player)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn check_players(
+    state: &mut ChessTestState,
+    game_id: usize,
+    white: TestAddress,
+    black: TestAddress,
+) {
+    let game = state.game(game_id);
+    assert_eq!(game.white_player, white.to_managed_address());
+    assert_eq!(game.black_player, black.to_managed_address());
+}
+
+#[test]
+fn payout_tables_must_add_up() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(
+        &mut state,
+        WHITE,
+        1,
+        SWISS,
+        ARMAGEDDON,
+        &[10_000],
+        Some("Only the owner or an organizer can create tournaments"),
+    );
+    create_tournament(
+        &mut state,
+        OWNER,
+        1,
+        SWISS,
+        ARMAGEDDON,
+        &[6_000, 3_000],
+        Some("Payout table must add up to 10000 basis points"),
+    );
+    create_tournament(
+        &mut state,
+        OWNER,
+        1,
+        SWISS,
+        ARMAGEDDON,
+        &[u32::MAX, 10_001],
+        Some("A payout share cannot exceed 10000 basis points"),
+    );
+
+    create_tournament(
+        &mut state,
+        OWNER,
+        1,
+        SWISS,
+        ARMAGEDDON,
+        &[7_000, 3_000],
+        None,
+    );
+    let tournament = state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .tournaments()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(tournament.tournament_id, 1);
+    assert_eq!(tournament.organizer, OWNER.to_managed_address());
+    assert_eq!(tournament.state, 0);
+}
+
+#[test]
+fn swiss_rounds_avoid_rematches() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(&mut state, OWNER, 2, SWISS, ARMAGEDDON, &[10_000], None);
+    register(&mut state, &[WHITE, BLACK, STRANGER, PARTNER]);
+    start_tournament(&mut state);
+
+    // nobody has points yet, so registration order decides
+    assert_eq!(round_games(&mut state, 1), vec![1, 2]);
+    check_players(&mut state, 1, WHITE, BLACK);
+    check_players(&mut state, 2, STRANGER, PARTNER);
+
+    resign(&mut state, BLACK, 1);
+    resign(&mut state, PARTNER, 2);
+    next_round(&mut state);
+
+    // the leaders meet, and black gets white when it is their turn to
+    assert_eq!(round_games(&mut state, 2), vec![3, 4]);
+    check_players(&mut state, 3, WHITE, STRANGER);
+    check_players(&mut state, 4, BLACK, PARTNER);
+    assert_eq!(points(&mut state, WHITE), 2);
+    assert_eq!(points(&mut state, PARTNER), 0);
+}
+
+#[test]
+fn swiss_pairings_look_ahead_to_avoid_rematches() {
+    let mut state = ChessTestState::new_active();
+    for player in [FIFTH, SIXTH] {
+        state
+            .world
+            .account(player)
+            .nonce(1)
+            .esdt_balance(WAGER_TOKEN, INITIAL_BALANCE);
+    }
+    create_tournament(&mut state, OWNER, 3, SWISS, ARMAGEDDON, &[10_000], None);
+    register(&mut state, &[WHITE, BLACK, STRANGER, PARTNER, FIFTH, SIXTH]);
+    start_tournament(&mut state);
+
+    resign(&mut state, WHITE, 1);
+    resign(&mut state, STRANGER, 2);
+    resign(&mut state, SIXTH, 3);
+    next_round(&mut state);
+    check_players(&mut state, 4, BLACK, PARTNER);
+    check_players(&mut state, 5, FIFTH, WHITE);
+    check_players(&mut state, 6, SIXTH, STRANGER);
+
+    resign(&mut state, BLACK, 4);
+    resign(&mut state, FIFTH, 5);
+    resign(&mut state, STRANGER, 6);
+    next_round(&mut state);
+
+    // pairing black with fifth would leave sixth and stranger, who already met
+    assert_eq!(round_games(&mut state, 3), vec![7, 8, 9]);
+    check_players(&mut state, 7, PARTNER, WHITE);
+    check_players(&mut state, 8, BLACK, SIXTH);
+    check_players(&mut state, 9, STRANGER, FIFTH);
+}
+
+#[test]
+fn swiss_players_meet_again_once_everyone_has_met() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(&mut state, OWNER, 2, SWISS, ARMAGEDDON, &[10_000], None);
+    register(&mut state, &[WHITE, BLACK]);
+    start_tournament(&mut state);
+
+    resign(&mut state, BLACK, 1);
+    next_round(&mut state);
+
+    // the rematch is played with reversed colors
+    assert_eq!(round_games(&mut state, 2), vec![2]);
+    check_players(&mut state, 2, BLACK, WHITE);
+}
+
+#[test]
+fn round_robin_gives_byes_to_odd_fields() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(
+        &mut state,
+        OWNER,
+        1,
+        ROUND_ROBIN,
+        ARMAGEDDON,
+        &[10_000],
+        None,
+    );
+    register(&mut state, &[WHITE, BLACK, STRANGER]);
+    start_tournament(&mut state);
+
+    assert_eq!(round_games(&mut state, 1), vec![1]);
+    check_players(&mut state, 1, STRANGER, BLACK);
+    assert_eq!(points(&mut state, WHITE), 2);
+}
+
+#[test]
+fn prizes_follow_the_standings() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(
+        &mut state,
+        OWNER,
+        2,
+        SWISS,
+        ARMAGEDDON,
+        &[7_000, 3_000],
+        None,
+    );
+    register(&mut state, &[WHITE, BLACK, STRANGER, PARTNER]);
+    start_tournament(&mut state);
+
+    resign(&mut state, BLACK, 1);
+    resign(&mut state, PARTNER, 2);
+    next_round(&mut state);
+    resign(&mut state, STRANGER, 3);
+    resign(&mut state, PARTNER, 4);
+    next_round(&mut state);
+
+    // black and the stranger both have 2 points, black registered first
+    state.check_balance(WHITE, INITIAL_BALANCE - ENTRY_FEE + 280);
+    state.check_balance(BLACK, INITIAL_BALANCE - ENTRY_FEE + 120);
+    state.check_balance(STRANGER, INITIAL_BALANCE - ENTRY_FEE);
+    state.check_balance(PARTNER, INITIAL_BALANCE - ENTRY_FEE);
+}
+
+#[test]
+fn drawn_knockout_games_get_an_armageddon_rematch() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(&mut state, OWNER, 1, KNOCKOUT, ARMAGEDDON, &[10_000], None);
+    register(&mut state, &[WHITE, BLACK, STRANGER, PARTNER]);
+    start_tournament(&mut state);
+
+    // without scores, seeds follow registration order: 1 vs 4 and 2 vs 3
+    check_players(&mut state, 1, WHITE, PARTNER);
+    check_players(&mut state, 2, BLACK, STRANGER);
+
+    // the rematch is played with reversed colors and black has draw odds
+    draw(&mut state, 1);
+    assert_eq!(round_games(&mut state, 1), vec![1, 2, 3]);
+    check_players(&mut state, 3, PARTNER, WHITE);
+    draw(&mut state, 3);
+    assert_eq!(points(&mut state, WHITE), 2);
+    assert_eq!(points(&mut state, PARTNER), 0);
+
+    // the final is paired once both semifinals are decided
+    assert_eq!(round_games(&mut state, 2), Vec::<usize>::new());
+    resign(&mut state, STRANGER, 2);
+    assert_eq!(round_games(&mut state, 2), vec![4]);
+    check_players(&mut state, 4, WHITE, BLACK);
+}
+
+#[test]
+fn the_champion_collects_the_prize_pool() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(
+        &mut state,
+        OWNER,
+        1,
+        KNOCKOUT,
+        BLACK_GOES_THROUGH,
+        &[7_000, 3_000],
+        Some("The champion collects the whole prize pool in knockout tournaments"),
+    );
+    create_tournament(
+        &mut state,
+        OWNER,
+        1,
+        KNOCKOUT,
+        BLACK_GOES_THROUGH,
+        &[10_000],
+        None,
+    );
+    register(&mut state, &[WHITE, BLACK, STRANGER, PARTNER]);
+    start_tournament(&mut state);
+
+    // without a rematch, black goes through on a draw
+    draw(&mut state, 1);
+    resign(&mut state, BLACK, 2);
+    assert_eq!(round_games(&mut state, 1), vec![1, 2]);
+    check_players(&mut state, 3, PARTNER, STRANGER);
+
+    resign(&mut state, PARTNER, 3);
+    state.check_balance(STRANGER, INITIAL_BALANCE - ENTRY_FEE + 4 * ENTRY_FEE);
+    for player in [WHITE, BLACK, PARTNER] {
+        state.check_balance(player, INITIAL_BALANCE - ENTRY_FEE);
+    }
+    state
+        .world
+        .check_account(CHESS_ADDRESS)
+        .esdt_balance(WAGER_TOKEN, 0);
+}
+
+#[test]
+fn organizers_run_their_own_tournaments() {
+    let mut state = ChessTestState::new_active();
+    set_organizer(&mut state, WHITE, WHITE, true);
+    set_organizer(&mut state, OWNER, PARTNER, true);
+    create_tournament(&mut state, PARTNER, 1, SWISS, ARMAGEDDON, &[10_000], None);
+    register(&mut state, &[WHITE, BLACK]);
+
+    // removed organizers keep the tournaments they created, but cannot create more
+    set_organizer(&mut state, OWNER, PARTNER, false);
+    create_tournament(
+        &mut state,
+        PARTNER,
+        1,
+        SWISS,
+        ARMAGEDDON,
+        &[10_000],
+        Some("Only the owner or an organizer can create tournaments"),
+    );
+    manage(
+        &mut state,
+        WHITE,
+        TournamentEndpoint::Cancel,
+        Some("Only the organizer can manage the tournament"),
+    );
+    manage(&mut state, PARTNER, TournamentEndpoint::Cancel, None);
+
+    // entry fees are refunded and the tournament is closed for good
+    state.check_balance(WHITE, INITIAL_BALANCE);
+    state.check_balance(BLACK, INITIAL_BALANCE);
+    pay_entry_fee(
+        &mut state,
+        STRANGER,
+        WAGER_TOKEN,
+        ENTRY_FEE,
+        Some("Registration is closed"),
+    );
+    manage(
+        &mut state,
+        OWNER,
+        TournamentEndpoint::Start,
+        Some("Tournament already started"),
+    );
+}
+
+#[test]
+fn entry_fees_must_be_paid_in_full() {
+    let mut state = ChessTestState::new_active();
+    pay_entry_fee(
+        &mut state,
+        WHITE,
+        WAGER_TOKEN,
+        ENTRY_FEE,
+        Some("Tournament does not exist"),
+    );
+    create_tournament(&mut state, OWNER, 1, SWISS, ARMAGEDDON, &[10_000], None);
+
+    pay_entry_fee(
+        &mut state,
+        WHITE,
+        WAGER_TOKEN,
+        ENTRY_FEE - 1,
+        Some("Wrong entry fee amount"),
+    );
+    pay_entry_fee(
+        &mut state,
+        WHITE,
+        OTHER_TOKEN,
+        ENTRY_FEE,
+        Some("Wrong entry fee token identifier"),
+    );
+    register(&mut state, &[WHITE]);
+    pay_entry_fee(
+        &mut state,
+        WHITE,
+        WAGER_TOKEN,
+        ENTRY_FEE,
+        Some("You are already registered"),
+    );
+    manage(
+        &mut state,
+        OWNER,
+        TournamentEndpoint::Start,
+        Some("Not enough players registered"),
+    );
+}
+
+#[test]
+fn free_tournaments_are_joined_without_a_payment() {
+    let mut state = ChessTestState::new_active();
+    state
+        .world
+        .tx()
+        .from(OWNER)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .create_tournament(
+            0u64,
+            1u32,
+            SWISS,
+            ARMAGEDDON,
+            MultiValueVec::from(vec![10_000u32]),
+        )
+        .run();
+
+    pay_entry_fee(
+        &mut state,
+        WHITE,
+        WAGER_TOKEN,
+        ENTRY_FEE,
+        Some("Wrong entry fee amount"),
+    );
+    for player in [WHITE, BLACK] {
+        state
+            .world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .register_for_tournament(1usize)
+            .run();
+    }
+
+    // nothing to refund either
+    manage(&mut state, OWNER, TournamentEndpoint::Cancel, None);
+    state.check_balance(WHITE, INITIAL_BALANCE);
+    state.check_balance(BLACK, INITIAL_BALANCE);
+}
+
+#[test]
+fn rounds_only_advance_once_they_are_over() {
+    let mut state = ChessTestState::new_active();
+    create_tournament(&mut state, OWNER, 1, SWISS, ARMAGEDDON, &[10_000], None);
+    register(&mut state, &[WHITE, BLACK]);
+    manage(
+        &mut state,
+        STRANGER,
+        TournamentEndpoint::NextRound,
+        Some("Tournament is not ongoing"),
+    );
+    start_tournament(&mut state);
+
+    manage(
+        &mut state,
+        STRANGER,
+        TournamentEndpoint::NextRound,
+        Some("Round is not over yet"),
+    );
+    resign(&mut state, BLACK, 1);
+    next_round(&mut state);
+    state.check_balance(WHITE, INITIAL_BALANCE - ENTRY_FEE + 2 * ENTRY_FEE);
+    manage(
+        &mut state,
+        STRANGER,
+        TournamentEndpoint::NextRound,
+        Some("Tournament is not ongoing"),
+    );
+
+    let mut state = ChessTestState::new_active();
+    create_tournament(&mut state, OWNER, 1, KNOCKOUT, ARMAGEDDON, &[10_000], None);
+    register(&mut state, &[WHITE, BLACK, STRANGER]);
+    manage(
+        &mut state,
+        OWNER,
+        TournamentEndpoint::Start,
+        Some("Knockout tournaments need a power of two players"),
+    );
+    register(&mut state, &[PARTNER]);
+    start_tournament(&mut state);
+    manage(
+        &mut state,
+        STRANGER,
+        TournamentEndpoint::NextRound,
+        Some("Knockout tournaments advance automatically"),
+    );
+}
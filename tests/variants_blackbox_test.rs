@@ -0,0 +1,169 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_core::chess960_board;
+use on_chain_chess_sc::on_chain_chess_sc_proxy;
+
+// with this seed the back rows are `RKBBNNRQ`
+const CHESS960_SEED: u8 = 3;
+
+fn chess960_game() -> ChessTestState {
+    let mut state = ChessTestState::new_active();
+    state.set_random_seed(CHESS960_SEED);
+    state.join_game_with_variant(WHITE, CHESS960);
+    state.join_game_with_variant(BLACK, CHESS960);
+    state
+}
+
+fn checks_given(state: &mut ChessTestState, player: u8) -> u8 {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .checks_given(1usize, player)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn chess960_starts_from_a_random_position() {
+    let mut state = chess960_game();
+    assert_eq!(state.game(1).variant, CHESS960);
+    assert_eq!(state.castling_rights(), 15);
+
+    let board = state.board();
+    assert_eq!(&board[56..], &[2, 0, 3, 3, 4, 4, 2, 1]);
+    for x in 0..8 {
+        assert_eq!(board[x], board[56 + x] + 7);
+        assert_eq!(board[8 + x], BLACK_PAWN);
+        assert_eq!(board[48 + x], WHITE_PAWN);
+    }
+
+    // the start position is kept, PGN exports start from it
+    let index = state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .chess960_index(1usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .unwrap();
+    assert_eq!(chess960_board(index), board);
+
+    // another seed, another position
+    state.set_random_seed(0);
+    state.join_game_with_variant(WHITE, CHESS960);
+    state.join_game_with_variant(BLACK, CHESS960);
+    assert_eq!(state.game(2).variant, CHESS960);
+    let other_board: [u8; 64] = state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .get_board(2usize)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_ne!(other_board, board);
+}
+
+#[test]
+fn standard_games_do_not_castle() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "e7e5", "g1f3", "g8f6", "f1c4", "f8c5"]);
+    state.illegal_move("e1g1", "Invalid king move");
+    state.illegal_move("e1h1", "Invalid king move");
+    assert_eq!(state.castling_rights(), 0);
+}
+
+#[test]
+fn chess960_castling() {
+    let mut state = chess960_game();
+    state.play(&["d2d4", "a7a6", "c1f4", "a6a5", "e2e4", "h7h6"]);
+
+    // the bishop on d1 is still in the way
+    state.illegal_move("b1a1", "Invalid king move");
+    state.play(&["d1f3", "h6h5"]);
+
+    // the king goes onto its rook and ends on c1, the rook on d1
+    state.play(&["b1a1"]);
+    assert_eq!(state.piece_at("a1"), EMPTY);
+    assert_eq!(state.piece_at("b1"), EMPTY);
+    assert_eq!(state.piece_at("c1"), WHITE_KING);
+    assert_eq!(state.piece_at("d1"), WHITE_ROOK);
+    assert_eq!(state.castling_rights(), 12);
+
+    // taking it back restores the rook and the castling rights
+    state.call(WHITE, GameEndpoint::RequestTakeback);
+    state.call(BLACK, GameEndpoint::AcceptTakeback);
+    assert_eq!(state.piece_at("a1"), WHITE_ROOK);
+    assert_eq!(state.piece_at("b1"), WHITE_KING);
+    assert_eq!(state.piece_at("c1"), EMPTY);
+    assert_eq!(state.piece_at("d1"), EMPTY);
+    assert_eq!(state.castling_rights(), 15);
+
+    state.move_in_notation(Notation::San("O-O-O"), None);
+    assert_eq!(state.piece_at("c1"), WHITE_KING);
+    assert_eq!(state.piece_at("d1"), WHITE_ROOK);
+
+    // moving the king gives up the other side as well
+    state.play(&["a5a4", "c1b1", "a4a3", "b1c1", "a3b2"]);
+    state.illegal_move("c1g1", "Invalid king move");
+}
+
+#[test]
+fn king_of_the_hill() {
+    let mut state = ChessTestState::new_with_variant_game(KING_OF_THE_HILL);
+    state.play(&["e2e4", "a7a6", "e1e2", "a6a5", "e2e3", "a5a4"]);
+    state.check_game_state(ONGOING);
+
+    state.play(&["e3d4"]);
+    state.check_game_state(WHITE_WON);
+    state.check_score(WHITE, 2);
+    state.check_balance(WHITE, INITIAL_BALANCE + WAGER);
+    state.check_balance(BLACK, INITIAL_BALANCE - WAGER);
+}
+
+#[test]
+fn king_of_the_hill_needs_a_safe_square() {
+    let mut state = ChessTestState::new_with_variant_game(KING_OF_THE_HILL);
+    state.play(&["e2e4", "e7e5", "e1e2", "a7a6", "e2e3", "a6a5"]);
+
+    // d4 is attacked by the pawn on e5, so black calls the move out instead
+    state.play(&["e3d4"]);
+    state.check_game_state(ONGOING);
+    state.call(BLACK, GameEndpoint::CallIllegalMove);
+    state.check_game_state(BLACK_WON);
+}
+
+#[test]
+fn three_check() {
+    let mut state = ChessTestState::new_with_variant_game(THREE_CHECK);
+    state.play(&["e2e4", "f7f6", "d1h5", "g7g6"]);
+    assert_eq!(checks_given(&mut state, 0), 1);
+
+    // a check taken back does not count
+    state.play(&["h5g6"]);
+    assert_eq!(checks_given(&mut state, 0), 2);
+    state.call(WHITE, GameEndpoint::RequestTakeback);
+    state.call(BLACK, GameEndpoint::AcceptTakeback);
+    assert_eq!(checks_given(&mut state, 0), 1);
+
+    state.play(&["h5g6", "h7g6", "a2a3", "d7d6"]);
+    state.check_game_state(ONGOING);
+    assert_eq!(checks_given(&mut state, 1), 0);
+
+    state.play(&["f1b5"]);
+    assert_eq!(checks_given(&mut state, 0), 3);
+    state.check_game_state(WHITE_WON);
+    state.check_balance(WHITE, INITIAL_BALANCE + WAGER);
+}
+
+#[test]
+fn variant_goals_do_not_apply_to_standard_games() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "a7a6", "e1e2", "a6a5", "e2e3", "a5a4", "e3d4"]);
+    state.check_game_state(ONGOING);
+}
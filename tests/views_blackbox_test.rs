@@ -0,0 +1,477 @@
+mod chess_setup;
+
+use chess_setup::*;
+use multiversx_sc_scenario::imports::*;
+use on_chain_chess_sc::on_chain_chess_sc_proxy::{self, MoveValidity};
+
+#[test]
+fn snapshot_of_a_new_game() {
+    let mut state = ChessTestState::new_with_game();
+    state.set_epoch(5);
+
+    let snapshot = state.snapshot();
+    assert_eq!(snapshot.game.state, ONGOING);
+    assert_eq!(snapshot.board, state.board());
+    assert_eq!(snapshot.move_count, 0);
+    assert!(snapshot.draw_offer.is_none());
+    assert!(snapshot.last_move.is_none());
+    assert_eq!(snapshot.current_epoch, 5);
+    assert_eq!(snapshot.move_deadline_epoch, 1);
+    assert!(!snapshot.in_check);
+    assert_eq!(snapshot.legal_moves.len(), 20);
+}
+
+#[test]
+fn snapshot_after_moves() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4", "f7f6", "d2d4", "g7g5"]);
+    state.call(WHITE, GameEndpoint::Draw);
+    state.play(&["d1h5"]);
+
+    let snapshot = state.snapshot();
+    assert_eq!(snapshot.game.player_turn, 1);
+    assert_eq!(snapshot.move_count, 5);
+    assert_eq!(snapshot.draw_offer.unwrap().move_number, 4);
+    let last_move = snapshot.last_move.unwrap();
+    assert_eq!(last_move.from, square("d1"));
+    assert_eq!(last_move.to, square("h5"));
+    assert_eq!(last_move.moved_piece, WHITE_QUEEN);
+
+    // black is mated, so there is nothing left to play
+    assert!(snapshot.in_check);
+    assert_eq!(snapshot.legal_moves.len(), 0);
+}
+
+#[test]
+fn snapshot_of_a_finished_game() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&["e2e4"]);
+    state.call(BLACK, GameEndpoint::Resign);
+
+    let snapshot = state.snapshot();
+    assert_eq!(snapshot.game.state, WHITE_WON);
+    assert_eq!(snapshot.white_score, 2);
+    assert_eq!(snapshot.black_score, 0);
+    assert_eq!(snapshot.legal_moves.len(), 0);
+}
+
+#[test]
+fn snapshot_of_a_missing_game() {
+    let mut state = ChessTestState::new_active();
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .get_game_snapshot(1usize)
+        .returns(ExpectError(4, "Game does not exist"))
+        .run();
+}
+
+fn ids(
+    games: MultiValueEncoded<StaticApi, on_chain_chess_sc_proxy::Game<StaticApi>>,
+) -> Vec<usize> {
+    games.into_iter().map(|game| game.game_id).collect()
+}
+
+/// Games 1 to 3, where `WHITE` plays the first two and game 1 is over.
+fn three_games() -> ChessTestState {
+    let mut state = ChessTestState::new_with_game();
+    state.join_game(STRANGER);
+    state.join_game(WHITE);
+    state.join_game(BLACK);
+    state.join_game(STRANGER);
+    state.call(BLACK, GameEndpoint::Resign);
+    state
+}
+
+#[test]
+fn games_in_range() {
+    let mut state = three_games();
+    let mut page = |offset: usize, limit: usize| {
+        ids(state
+            .world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .get_games_in_range(offset, limit)
+            .returns(ReturnsResult)
+            .run())
+    };
+
+    assert_eq!(page(0, 2), vec![1, 2]);
+    assert_eq!(page(2, 2), vec![3]);
+    assert_eq!(page(5, 2), Vec::<usize>::new());
+    assert_eq!(page(0, 0), Vec::<usize>::new());
+}
+
+#[test]
+fn games_by_player() {
+    let mut state = three_games();
+    let mut page = |player: TestAddress, offset: usize, limit: usize| {
+        ids(state
+            .world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .get_games_by_player(player, offset, limit)
+            .returns(ReturnsResult)
+            .run())
+    };
+
+    assert_eq!(page(WHITE, 0, 10), vec![1, 2]);
+    assert_eq!(page(WHITE, 1, 10), vec![2]);
+    assert_eq!(page(BLACK, 0, 1), vec![1]);
+    assert_eq!(page(STRANGER, 0, 10), vec![2, 3]);
+    assert_eq!(page(OWNER, 0, 10), Vec::<usize>::new());
+}
+
+#[test]
+fn games_by_state() {
+    let mut state = three_games();
+    let mut page = |ongoing: bool, offset: usize, limit: usize| {
+        let mut game_ids = ids(state
+            .world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .get_games_by_state(ongoing, offset, limit)
+            .returns(ReturnsResult)
+            .run());
+        game_ids.sort();
+        game_ids
+    };
+
+    assert_eq!(page(true, 0, 10), vec![2, 3]);
+    assert_eq!(page(true, 1, 10).len(), 1);
+    assert_eq!(page(false, 0, 10), vec![1]);
+    assert_eq!(page(false, 1, 10), Vec::<usize>::new());
+}
+
+#[test]
+fn finished_games_come_in_the_order_they_ended() {
+    let mut state = three_games();
+    for (player, game_id) in [(STRANGER, 3usize), (WHITE, 2)] {
+        state
+            .world
+            .tx()
+            .from(player)
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .resign(game_id)
+            .run();
+    }
+    let mut page = |offset: usize, limit: usize| {
+        ids(state
+            .world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .get_games_by_state(false, offset, limit)
+            .returns(ReturnsResult)
+            .run())
+    };
+
+    assert_eq!(page(0, 10), vec![1, 3, 2]);
+    assert_eq!(page(1, 1), vec![3]);
+}
+
+#[test]
+fn page_size_is_limited() {
+    let mut state = three_games();
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .get_games_in_range(0usize, 101usize)
+        .returns(ExpectError(4, "Page is too large"))
+        .run();
+}
+
+fn legal_moves_from(state: &mut ChessTestState, from: &str) -> Vec<String> {
+    let moves = state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .get_legal_moves_from(1usize, square(from))
+        .returns(ReturnsResult)
+        .run();
+
+    let mut targets: Vec<String> = moves.iter().map(|mv| name(mv.to)).collect();
+    targets.sort();
+    targets
+}
+
+fn name(square: u8) -> String {
+    format!("{}{}", (b'a' + square % 8) as char, 8 - square / 8)
+}
+
+#[test]
+fn legal_moves() {
+    let mut state = ChessTestState::new_with_game();
+    let count = |state: &mut ChessTestState| {
+        state
+            .world
+            .query()
+            .to(CHESS_ADDRESS)
+            .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+            .get_legal_moves(1usize)
+            .returns(ReturnsResult)
+            .run()
+            .len()
+    };
+
+    assert_eq!(count(&mut state), 20);
+    assert_eq!(legal_moves_from(&mut state, "g1"), vec!["f3", "h3"]);
+    assert_eq!(legal_moves_from(&mut state, "e2"), vec!["e3", "e4"]);
+    // black pieces cannot move on white's turn
+    assert!(legal_moves_from(&mut state, "e7").is_empty());
+
+    state.play(&["e2e4", "d7d5", "f1b5"]);
+    assert_eq!(count(&mut state), 5);
+    // the king is in check, only blocking or stepping away is left
+    assert_eq!(legal_moves_from(&mut state, "c7"), vec!["c6"]);
+    assert!(legal_moves_from(&mut state, "a7").is_empty());
+    assert_eq!(legal_moves_from(&mut state, "e8"), Vec::<String>::new());
+    assert_eq!(legal_moves_from(&mut state, "d8"), vec!["d7"]);
+}
+
+#[test]
+fn legal_moves_include_every_promotion() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&[
+        "h2h4", "g7g5", "h4g5", "h7h6", "g5h6", "b8c6", "h6h7", "c6d4",
+    ]);
+
+    let moves = state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .get_legal_moves_from(1usize, square("h7"))
+        .returns(ReturnsResult)
+        .run();
+    // the rook on h8 blocks the push, only the capture on g8 is left
+    assert_eq!(moves.len(), 4);
+    assert!(moves.iter().all(|mv| mv.promotion.is_some()));
+}
+
+#[test]
+fn legal_moves_from_outside_the_board() {
+    let mut state = ChessTestState::new_with_game();
+
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .get_legal_moves_from(1usize, 64u8)
+        .returns(ExpectError(4, "Square is not on the board"))
+        .run();
+}
+
+fn validate(
+    state: &mut ChessTestState,
+    player: TestAddress,
+    mv: &str,
+    promotion: Option<u8>,
+) -> MoveValidity {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .validate_move(
+            1usize,
+            player,
+            square(&mv[..2]),
+            square(&mv[2..]),
+            OptionalValue::from(promotion),
+        )
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn validate_move_reasons() {
+    let mut state = ChessTestState::new_with_game();
+
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e4", None),
+        MoveValidity::Valid
+    );
+    assert_eq!(
+        validate(&mut state, BLACK, "e7e5", None),
+        MoveValidity::NotYourTurn
+    );
+    assert_eq!(
+        validate(&mut state, STRANGER, "e2e4", None),
+        MoveValidity::NotAPlayer
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "e3e4", None),
+        MoveValidity::EmptySquare
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "e7e5", None),
+        MoveValidity::WrongColor
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "d1d2", None),
+        MoveValidity::OwnPieceOnTarget
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e2", None),
+        MoveValidity::NothingMoved
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e5", None),
+        MoveValidity::InvalidPieceMove
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "g1g3", None),
+        MoveValidity::InvalidPieceMove
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "f1c4", None),
+        MoveValidity::BlockedPath
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "a1a4", None),
+        MoveValidity::BlockedPath
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e4", Some(1)),
+        MoveValidity::InvalidPromotion
+    );
+
+    state.play(&["e2e4", "d7d5", "f1b5"]);
+    // the king is in check, so moving anything else leaves it there
+    assert_eq!(
+        validate(&mut state, BLACK, "a7a6", None),
+        MoveValidity::LeavesKingInCheck
+    );
+    assert_eq!(
+        validate(&mut state, BLACK, "c7c6", None),
+        MoveValidity::Valid
+    );
+
+    state.set_epoch(5);
+    assert_eq!(
+        validate(&mut state, BLACK, "c7c6", None),
+        MoveValidity::TimeExpired
+    );
+}
+
+#[test]
+fn validate_move_in_a_blind_opening() {
+    let mut state = ChessTestState::new_with_variant_game(BLIND_OPENING);
+
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e4", None),
+        MoveValidity::MoveNotCommitted
+    );
+    state
+        .world
+        .tx()
+        .from(WHITE)
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .make_move(1usize, square("e2"), square("e4"))
+        .returns(ExpectError(
+            4,
+            "Moves of the blind opening are committed first",
+        ))
+        .run();
+}
+
+#[test]
+fn validate_move_promotions() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&[
+        "h2h4", "g7g5", "h4g5", "h7h6", "g5h6", "b8c6", "h6h7", "c6d4",
+    ]);
+
+    // like makeMove, no piece means a queen
+    assert_eq!(
+        validate(&mut state, WHITE, "h7g8", None),
+        MoveValidity::Valid
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "h7g8", Some(4)),
+        MoveValidity::Valid
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "h7g8", Some(0)),
+        MoveValidity::InvalidPromotion
+    );
+    assert_eq!(
+        validate(&mut state, WHITE, "h7g8", Some(5)),
+        MoveValidity::InvalidPromotion
+    );
+}
+
+#[test]
+fn validate_move_outside_a_game() {
+    let mut state = ChessTestState::new_active();
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e4", None),
+        MoveValidity::GameNotFound
+    );
+
+    let mut state = ChessTestState::new_with_game();
+    state.call(WHITE, GameEndpoint::Resign);
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e4", None),
+        MoveValidity::GameNotActive
+    );
+
+    let mut state = ChessTestState::new_with_game();
+    state.pause();
+    assert_eq!(
+        validate(&mut state, WHITE, "e2e4", None),
+        MoveValidity::ContractPaused
+    );
+}
+
+fn move_history(state: &mut ChessTestState) -> Vec<(u8, u8, Option<u8>)> {
+    state
+        .world
+        .query()
+        .to(CHESS_ADDRESS)
+        .typed(on_chain_chess_sc_proxy::OnChainChessScProxy)
+        .move_history(1usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|mv| (mv.from, mv.to, mv.promotion))
+        .collect()
+}
+
+#[test]
+fn move_history_follows_moves_and_takebacks() {
+    let mut state = ChessTestState::new_with_game();
+    state.play(&[
+        "h2h4", "g7g5", "h4g5", "h7h6", "g5h6", "b8c6", "h6h7", "c6d4",
+    ]);
+    state.move_in_notation(Notation::Uci("h7g8n"), None);
+
+    let history = move_history(&mut state);
+    assert_eq!(history.len(), 9);
+    assert_eq!(history[0], (square("h2"), square("h4"), None));
+    assert_eq!(history[8], (square("h7"), square("g8"), Some(4)));
+
+    state.call(WHITE, GameEndpoint::RequestTakeback);
+    state.call(BLACK, GameEndpoint::AcceptTakeback);
+    assert_eq!(move_history(&mut state).len(), 8);
+
+    state.play(&["h7g8"]);
+    assert_eq!(
+        move_history(&mut state)[8],
+        (square("h7"), square("g8"), Some(1))
+    );
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           17
-// Async Callback (empty):               1
-// Total number of exported functions:  20
+// Endpoints:                           81
+// Async Callback:                       1
+// Total number of exported functions:  84
 
 #![no_std]
 
@@ -20,24 +20,88 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         upgrade => upgrade
+        migrateGames => migrate_games
         isPaused => is_paused
         getWagerTokenId => wager_token_id
         getWagerAmount => wager_amount
         getScore => score
         getIsPlayerWaitingOpt => is_player_waiting_opt
+        getWaitingPlayer => waiting_player
+        getBughouseQueue => bughouse_queue
+        getMigratedGames => migrated_games
         getGames => games
-        getBoard => board
+        getCastlingRights => castling_rights
+        getChess960Index => chess960_index
+        getChecksGiven => checks_given
+        getMoveCommitment => move_commitment
+        getRevealedMove => revealed_move
+        getLinkedGame => linked_game
+        getReserve => reserve
+        getMoveCount => move_count
         getDrawOffer => draw_offer
+        getLastMove => last_move
+        getMoveHistory => move_history
+        getTakebackRequest => takeback_request
+        getMaxPracticeWager => max_practice_wager
+        getHousePool => house_pool
+        getPracticeWager => practice_wager
+        getSideBetFee => side_bet_fee
+        getSidePool => side_pool
+        getSideBet => side_bet
+        getSidePayout => side_payout
+        getResultCollection => result_collection
+        getWantsResultNft => wants_result_nft
+        getTournamentOrganizers => tournament_organizers
+        getTournaments => tournaments
+        getTournamentPlayers => tournament_players
+        getTournamentPoints => tournament_points
+        getTournamentRoundGames => tournament_round_games
+        getGameTournament => game_tournament
+        getBracketWinner => bracket_winner
+        isArmageddon => is_armageddon
         pause => pause
         unpause => unpause
         setWager => set_wager
+        addTournamentOrganizer => add_tournament_organizer
+        removeTournamentOrganizer => remove_tournament_organizer
+        setMaxPracticeWager => set_max_practice_wager
+        withdrawHousePool => withdraw_house_pool
+        setSideBetFee => set_side_bet_fee
+        getBoard => get_board
         joinGame => join_game
         makeMove => make_move
+        makeMoveUci => make_move_uci
+        makeMoveSan => make_move_san
+        dropPiece => drop_piece
+        commitMove => commit_move
+        revealMove => reveal_move
+        requestTakeback => request_takeback
+        acceptTakeback => accept_takeback
         draw => draw
+        withdrawDrawOffer => withdraw_draw_offer
         callIllegalMove => call_illegal_move
         signalInactivity => signal_inactivity
         resign => resign
+        abortGame => abort_game
+        startPracticeGame => start_practice_game
+        issueResultCollection => issue_result_collection
+        setWantsResultNft => set_wants_result_nft
+        placeSideBet => place_side_bet
+        claimSideBet => claim_side_bet
+        createTournament => create_tournament
+        registerForTournament => register_for_tournament
+        cancelTournament => cancel_tournament
+        startTournament => start_tournament
+        nextRound => next_round
+        getTournamentStandings => get_tournament_standings
+        getGameSnapshot => get_game_snapshot
+        getLegalMoves => get_legal_moves
+        getLegalMovesFrom => get_legal_moves_from
+        validateMove => validate_move
+        getGamesInRange => get_games_in_range
+        getGamesByPlayer => get_games_by_player
+        getGamesByState => get_games_by_state
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { on_chain_chess_sc }